@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+
+const CLR_1: Color = Color::srgb(0.168, 0.168, 0.168);
+const CLR_2: Color = Color::srgb(0.109, 0.109, 0.109);
+const CLR_3: Color = Color::srgb(0.569, 0.592, 0.647);
+const CLR_4: Color = Color::srgb(0.902, 0.4, 0.004);
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, ScrollViewPlugin))
+        .add_systems(Startup, prepare)
+        .run();
+}
+
+fn bar(p: &mut ChildBuilder, label: &str, marker: impl Component) {
+    p.spawn((
+        NodeBundle {
+            style: Style {
+                padding: UiRect::all(Val::Px(15.0)),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            background_color: CLR_4.into(),
+            ..default()
+        },
+        marker,
+    ))
+    .with_children(|p| {
+        p.spawn(TextBundle::from_section(
+            label,
+            TextStyle {
+                font_size: 25.0,
+                color: CLR_2,
+                ..default()
+            },
+        ));
+    });
+}
+
+fn prepare(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(15.0)),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            background_color: CLR_1.into(),
+            ..default()
+        })
+        .with_children(|p| {
+            // The frame keeps the header and footer in place, only the view between them scrolls.
+            p.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Px(400.0),
+                        height: Val::Percent(80.0),
+                        ..default()
+                    },
+                    background_color: CLR_2.into(),
+                    ..default()
+                },
+                ScrollFrame,
+            ))
+            .with_children(|p| {
+                bar(p, "Header", ScrollHeader);
+                p.spawn((NodeBundle::default(), ScrollView::default()))
+                    .with_children(|p| {
+                        p.spawn((
+                            NodeBundle {
+                                style: Style {
+                                    flex_direction: FlexDirection::Column,
+                                    width: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            ScrollableContent::default(),
+                        ))
+                        .with_children(|scroll_area| {
+                            for i in 0..30 {
+                                scroll_area
+                                    .spawn(NodeBundle {
+                                        style: Style {
+                                            margin: UiRect::all(Val::Px(10.0)),
+                                            padding: UiRect::all(Val::Px(20.0)),
+                                            border: UiRect::all(Val::Px(3.0)),
+                                            ..default()
+                                        },
+                                        border_color: CLR_3.into(),
+                                        ..default()
+                                    })
+                                    .with_children(|p| {
+                                        p.spawn(TextBundle::from_section(
+                                            format!("Row {}", i),
+                                            TextStyle {
+                                                font_size: 20.0,
+                                                color: CLR_3,
+                                                ..default()
+                                            },
+                                        ));
+                                    });
+                            }
+                        });
+                    });
+                bar(p, "Footer", ScrollFooter);
+            });
+        });
+}
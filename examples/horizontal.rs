@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+
+const CLR_1: Color = Color::srgb(0.168, 0.168, 0.168);
+const CLR_2: Color = Color::srgb(0.109, 0.109, 0.109);
+const CLR_3: Color = Color::srgb(0.569, 0.592, 0.647);
+const CLR_4: Color = Color::srgb(0.902, 0.4, 0.004);
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, ScrollViewPlugin))
+        .add_systems(Startup, prepare)
+        .run();
+}
+
+fn prepare(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(15.0)),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            background_color: CLR_1.into(),
+            ..default()
+        })
+        .with_children(|p| {
+            p.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(80.0),
+                        height: Val::Px(240.0),
+                        ..default()
+                    },
+                    background_color: CLR_2.into(),
+                    ..default()
+                },
+                ScrollView::horizontal(40.0),
+            ))
+            .with_children(|p| {
+                // The content lays out the cards in a row and grows with them,
+                // so it overflows the view horizontally.
+                p.spawn((
+                    NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Row,
+                            height: Val::Percent(100.0),
+                            align_items: AlignItems::Center,
+                            padding: UiRect::horizontal(Val::Px(15.0)),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    ScrollableContent::default(),
+                ))
+                .with_children(|scroll_area| {
+                    for i in 0..20 {
+                        scroll_area
+                            .spawn(NodeBundle {
+                                style: Style {
+                                    // Without a minimal width the cards are squashed
+                                    // to fit the row instead of overflowing it.
+                                    min_width: Val::Px(160.0),
+                                    height: Val::Px(180.0),
+                                    margin: UiRect::horizontal(Val::Px(15.0)),
+                                    border: UiRect::all(Val::Px(5.0)),
+                                    align_items: AlignItems::Center,
+                                    justify_content: JustifyContent::Center,
+                                    ..default()
+                                },
+                                background_color: CLR_2.into(),
+                                border_color: CLR_4.into(),
+                                ..default()
+                            })
+                            .with_children(|p| {
+                                p.spawn(TextBundle::from_section(
+                                    format!("Card {}", i),
+                                    TextStyle {
+                                        font_size: 25.0,
+                                        color: CLR_3,
+                                        ..default()
+                                    },
+                                ));
+                            });
+                    }
+                });
+            });
+        });
+}
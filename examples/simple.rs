@@ -7,6 +7,9 @@ const CLR_3: Color = Color::srgb(0.569, 0.592, 0.647);
 const CLR_4: Color = Color::srgb(0.902, 0.4, 0.004);
 const CLR_5: Color = Color::srgb(0.2, 0.3, 0.4);
 
+#[derive(Component)]
+struct MainScrollView;
+
 fn main() {
     App::new()
         .add_plugins((DefaultPlugins, ScrollViewPlugin))
@@ -55,6 +58,7 @@ fn prepare(mut commands: Commands) {
             });
             // Main vertical scroll view
             p.spawn((
+                MainScrollView,
                 NodeBundle {
                     style: Style {
                         width: Val::Percent(80.0),
@@ -110,75 +114,52 @@ fn prepare(mut commands: Commands) {
                                 ScrollableContent::default(),
                             ))
                             .with_children(|p| {
-                                // Horizontal scroll view
-                                p.spawn((
-                                    NodeBundle {
-                                        style: Style {
-                                            width: Val::Percent(95.0),
-                                            margin: UiRect::all(Val::Px(10.0)),
-                                            border: UiRect::all(Val::Px(3.0)),
-                                            padding: UiRect::all(Val::Px(20.0)),
-                                            height: Val::Px(80.0),
-                                            ..default()
-                                        },
-                                        background_color: CLR_3.into(),
-                                        ..default()
-                                    },
-                                    ScrollView {
-                                        horizontal: true,
+                                // Horizontal scroll view, spawned with the helper creating
+                                // the `ScrollableContent` laid out in a row.
+                                let view = NodeBundle {
+                                    style: Style {
+                                        width: Val::Percent(95.0),
+                                        margin: UiRect::all(Val::Px(10.0)),
+                                        border: UiRect::all(Val::Px(3.0)),
+                                        padding: UiRect::all(Val::Px(20.0)),
+                                        height: Val::Px(80.0),
                                         ..default()
                                     },
-                                ))
-                                .with_children(|p| {
-                                    p.spawn((
-                                        NodeBundle {
-                                            style: Style {
-                                                flex_direction: FlexDirection::Row,
-                                                height: Val::Percent(100.0),
-                                                align_items: AlignItems::Center,
+                                    background_color: CLR_3.into(),
+                                    ..default()
+                                };
+                                let scroll_view = ScrollView::horizontal(40.0);
+                                p.spawn_scroll_view(view, scroll_view, |scroll_area| {
+                                    for i in 0..10 {
+                                        scroll_area
+                                            .spawn(NodeBundle {
+                                                style: Style {
+                                                    height: Val::Px(60.0),
+                                                    margin: UiRect::horizontal(Val::Px(15.0)),
+                                                    border: UiRect::all(Val::Px(5.0)),
+                                                    padding: UiRect::all(Val::Px(10.0)),
+                                                    align_items: AlignItems::Center,
+                                                    justify_content: JustifyContent::Center,
+                                                    ..default()
+                                                },
+                                                background_color: CLR_2.into(),
+                                                border_color: CLR_4.into(),
                                                 ..default()
-                                            },
-                                            ..default()
-                                        },
-                                        ScrollableContent::default(),
-                                    ))
-                                    .with_children(
-                                        |scroll_area| {
-                                            // Add horizontal scroll content
-                                            for i in 0..10 {
-                                                scroll_area
-                                                    .spawn(NodeBundle {
-                                                        style: Style {
-                                                            height: Val::Px(60.0),
-                                                            margin: UiRect::horizontal(Val::Px(
-                                                                15.0,
-                                                            )),
-                                                            border: UiRect::all(Val::Px(5.0)),
-                                                            padding: UiRect::all(Val::Px(10.0)),
-                                                            align_items: AlignItems::Center,
-                                                            justify_content: JustifyContent::Center,
+                                            })
+                                            .with_children(|p| {
+                                                p.spawn(
+                                                    TextBundle::from_section(
+                                                        format!("Horizontal {}", i),
+                                                        TextStyle {
+                                                            font_size: 16.0,
+                                                            color: CLR_3,
                                                             ..default()
                                                         },
-                                                        background_color: CLR_2.into(),
-                                                        border_color: CLR_4.into(),
-                                                        ..default()
-                                                    })
-                                                    .with_children(|p| {
-                                                        p.spawn(
-                                                            TextBundle::from_section(
-                                                                format!("Horizontal {}", i),
-                                                                TextStyle {
-                                                                    font_size: 16.0,
-                                                                    color: CLR_3,
-                                                                    ..default()
-                                                                },
-                                                            )
-                                                            .with_text_justify(JustifyText::Center),
-                                                        );
-                                                    });
-                                            }
-                                        },
-                                    );
+                                                    )
+                                                    .with_text_justify(JustifyText::Center),
+                                                );
+                                            });
+                                    }
                                 });
                                 // Add content to nested vertical scroll view
                                 for i in 0..11 {
@@ -246,13 +227,13 @@ fn prepare(mut commands: Commands) {
 
 fn reset_scroll(
     q: Query<(&Button, &Interaction), Changed<Interaction>>,
-    mut scrolls_q: Query<&mut ScrollableContent>,
+    main_view: Query<Entity, With<MainScrollView>>,
+    mut scroll_to_top: EventWriter<ScrollToTop>,
 ) {
     for (_, interaction) in q.iter() {
         if interaction == &Interaction::Pressed {
-            for mut scroll in scrolls_q.iter_mut() {
-                scroll.pos_y = 0.0;
-                scroll.pos_x = 0.0;
+            for view in main_view.iter() {
+                scroll_to_top.send(ScrollToTop { view });
             }
         }
     }
@@ -0,0 +1,40 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    prelude::*,
+};
+
+use crate::ScrollMetrics;
+
+/// Reports the scroll views as scrollable containers to the assistive technologies,
+/// together with their current scroll position and extents.
+pub fn update_accessibility_node(
+    mut commands: Commands,
+    mut q: Query<(Entity, &ScrollMetrics, Option<&mut AccessibilityNode>), Changed<ScrollMetrics>>,
+) {
+    for (entity, metrics, node) in q.iter_mut() {
+        match node {
+            Some(mut node) => set_scroll_properties(&mut node, metrics),
+            None => {
+                let mut node = NodeBuilder::new(Role::ScrollView);
+                set_scroll_properties(&mut node, metrics);
+                commands
+                    .entity(entity)
+                    .insert(AccessibilityNode::from(node));
+            }
+        }
+    }
+}
+
+fn set_scroll_properties(node: &mut NodeBuilder, metrics: &ScrollMetrics) {
+    // The content offset is negative, AccessKit expects the distance scrolled from the start.
+    node.set_clips_children();
+    node.set_scroll_x(-metrics.offset.x as f64);
+    node.set_scroll_x_min(0.0);
+    node.set_scroll_x_max(metrics.max_scroll.x as f64);
+    node.set_scroll_y(-metrics.offset.y as f64);
+    node.set_scroll_y_min(0.0);
+    node.set_scroll_y_max(metrics.max_scroll.y as f64);
+}
@@ -0,0 +1,194 @@
+use bevy::{input::touch::Touch, prelude::*, window::PrimaryWindow};
+
+use crate::{
+    handle_scroll_for_view, pointer::ScrollInteraction, ScrollConsumed, ScrollView,
+    ScrollableContent, SmoothScroll,
+};
+
+/// Component for the `ScrollView` entity scrolling its content automatically,
+/// e.g. for attract loops on kiosks and signage.
+///
+/// Any user input to the view pauses the scrolling for `resume_delay` seconds.
+#[derive(Component, Debug, Reflect, Clone)]
+pub struct AutoScroll {
+    /// Speed in pixels per second, positive values scroll towards the end of the content.
+    /// `AutoScrollMode::Reverse` flips its sign at the boundaries.
+    pub velocity: Vec2,
+    /// What happens when the content reaches its end.
+    pub mode: AutoScrollMode,
+    /// Time in seconds after the last user input before the scrolling resumes.
+    pub resume_delay: f32,
+    resume_at: f64,
+}
+
+impl AutoScroll {
+    /// Creates the component scrolling with given `velocity` and `mode`,
+    /// resuming 2 seconds after the user input.
+    pub fn new(velocity: Vec2, mode: AutoScrollMode) -> Self {
+        Self {
+            velocity,
+            mode,
+            resume_delay: 2.0,
+            resume_at: 0.0,
+        }
+    }
+}
+
+/// Behavior of the `AutoScroll` at the boundaries of the content.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum AutoScrollMode {
+    /// Stops at the boundary.
+    #[default]
+    Stop,
+    /// Jumps back to the opposite boundary and continues.
+    Loop,
+    /// Reverses the direction, scrolling back and forth.
+    Reverse,
+}
+
+/// Component for the `ScrollView` entity scrolling its content while something is dragged
+/// near its edges, e.g. to drop an item of a reorderable list beyond the visible part.
+///
+/// It is active only while the `EdgeAutoScrollActive` resource is set, the app sets it
+/// when its drag starts and clears it on the drop. The pointer within `edge_margin` of
+/// an edge scrolls the view towards that edge, faster the closer it is to the edge.
+#[derive(Component, Debug, Reflect, Clone)]
+pub struct EdgeAutoScroll {
+    /// Distance in pixels from the edges of the view in which the pointer scrolls it.
+    /// Default is 40.
+    pub edge_margin: f32,
+    /// Speed in pixels per second with the pointer at the very edge of the view.
+    /// Default is 600.
+    pub auto_scroll_speed: f32,
+}
+
+impl Default for EdgeAutoScroll {
+    fn default() -> Self {
+        Self {
+            edge_margin: 40.0,
+            auto_scroll_speed: 600.0,
+        }
+    }
+}
+
+/// Resource enabling the `EdgeAutoScroll` of the views, set it while the app drags something.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub struct EdgeAutoScrollActive(pub bool);
+
+/// Scrolls the views with `EdgeAutoScroll` while the pointer is near their edges
+/// and `EdgeAutoScrollActive` is set.
+pub fn edge_auto_scroll(
+    time: Res<Time>,
+    active: Res<EdgeAutoScrollActive>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    touches: Res<Touches>,
+    ui_scale: Res<UiScale>,
+    views: Query<(
+        &ScrollView,
+        &EdgeAutoScroll,
+        &Node,
+        &GlobalTransform,
+        &Children,
+    )>,
+    mut content_q: Query<(&mut ScrollableContent, &Node)>,
+) {
+    if !active.0 {
+        return;
+    }
+    let Some(pointer) = windows
+        .get_single()
+        .ok()
+        .and_then(Window::cursor_position)
+        .or_else(|| touches.iter().next().map(Touch::position))
+        .map(|pointer| pointer / ui_scale.0)
+    else {
+        return;
+    };
+    for (scroll_view, edge, node, transform, children) in views.iter() {
+        if !scroll_view.enabled || edge.edge_margin <= 0.0 {
+            continue;
+        }
+        let rect = node.logical_rect(transform);
+        if !rect.contains(pointer) {
+            continue;
+        }
+        // How deep the pointer is in the margin of an edge, from 0 to 1 at the edge.
+        let depth = |distance: f32| (1.0 - distance / edge.edge_margin).clamp(0.0, 1.0);
+        let towards_end = Vec2::new(
+            depth(rect.max.x - pointer.x) - depth(pointer.x - rect.min.x),
+            depth(rect.max.y - pointer.y) - depth(pointer.y - rect.min.y),
+        );
+        if towards_end == Vec2::ZERO {
+            continue;
+        }
+        // Revealing the content below the bottom edge moves the content up.
+        let delta = -towards_end * edge.auto_scroll_speed * time.delta_seconds();
+        let delta = scroll_view.physical_delta(delta);
+        handle_scroll_for_view(children, scroll_view, node, delta, &mut content_q);
+    }
+}
+
+/// Moves the content of the views with `AutoScroll`.
+pub fn auto_scroll(
+    time: Res<Time>,
+    mut consumed_evr: EventReader<ScrollConsumed>,
+    mut views: Query<(
+        Entity,
+        &ScrollView,
+        &ScrollInteraction,
+        &Node,
+        &Children,
+        &mut AutoScroll,
+    )>,
+    mut content_q: Query<(&mut ScrollableContent, &Node, Has<SmoothScroll>)>,
+) {
+    let now = time.elapsed_seconds_f64();
+    let offered: Vec<Entity> = consumed_evr.read().map(|ev| ev.entity).collect();
+    for (entity, scroll_view, interaction, node, children, mut auto) in views.iter_mut() {
+        if interaction.0 == Interaction::Pressed || offered.contains(&entity) {
+            auto.resume_at = now + auto.resume_delay as f64;
+        }
+        if now < auto.resume_at {
+            continue;
+        }
+        for &child in children.iter() {
+            let Ok((mut scroll, content_node, animated)) = content_q.get_mut(child) else {
+                continue;
+            };
+            if animated {
+                continue;
+            }
+            let max_scroll = scroll_view.max_scroll(content_node.size(), node.size());
+            let mode = auto.mode;
+            let step = auto.velocity * time.delta_seconds();
+            if scroll_view.axis.scrolls_vertically() {
+                let velocity = &mut auto.velocity.y;
+                scroll.pos_y = advance(scroll.pos_y, step.y, max_scroll.y, mode, velocity);
+            }
+            if scroll_view.axis.scrolls_horizontally() {
+                let velocity = &mut auto.velocity.x;
+                scroll.pos_x = advance(scroll.pos_x, step.x, max_scroll.x, mode, velocity);
+            }
+        }
+    }
+}
+
+/// Moves a single axis by `step` towards the end and applies the `mode` at the boundaries.
+fn advance(pos: f32, step: f32, max_scroll: f32, mode: AutoScrollMode, velocity: &mut f32) -> f32 {
+    if step == 0.0 || max_scroll <= 0.0 {
+        return pos;
+    }
+    let new_pos = pos - step;
+    if (-max_scroll..=0.0).contains(&new_pos) {
+        return new_pos;
+    }
+    match mode {
+        AutoScrollMode::Stop => new_pos.clamp(-max_scroll, 0.0),
+        AutoScrollMode::Loop if new_pos < -max_scroll => 0.0,
+        AutoScrollMode::Loop => -max_scroll,
+        AutoScrollMode::Reverse => {
+            *velocity = -*velocity;
+            new_pos.clamp(-max_scroll, 0.0)
+        }
+    }
+}
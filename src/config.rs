@@ -0,0 +1,249 @@
+use bevy::prelude::*;
+
+/// Input options of the `ScrollView`.
+#[derive(Debug, Clone, Reflect)]
+pub struct ScrollInput {
+    /// When `axis` is `ScrollAxis::Both` and the content overflows only horizontally,
+    /// the vertical mouse wheel scrolls the horizontal axis instead.
+    /// Default is true.
+    pub wheel_axis_fallback: bool,
+    /// When `axis` is `ScrollAxis::Horizontal`, the vertical mouse wheel scrolls the view.
+    /// Horizontal wheel input (e.g. tilting the wheel or a touchpad) always takes precedence,
+    /// the vertical wheel is used only when there is none. When disabled, the vertical
+    /// wheel is left to the parent scroll views.
+    /// Default is true.
+    pub wheel_y_scrolls_horizontal: bool,
+    /// Flips the direction of the mouse wheel scrolling. Default is false.
+    pub invert_wheel: bool,
+    /// Flips the direction of the scrolling by dragging with the mouse.
+    ///
+    /// By default the content follows the pointer, like grabbing and pulling it: dragging
+    /// up moves the content up and reveals the content below. When inverted, the content
+    /// moves against the pointer, like dragging a scrollbar thumb. Independent of
+    /// `invert_wheel`.
+    /// Default is false.
+    pub invert_drag: bool,
+    /// Flips the direction of the scrolling by touch, with the same convention as
+    /// `invert_drag`.
+    /// Default is false.
+    pub invert_touch: bool,
+    /// Distance in pixels the touch has to travel from where it started before it scrolls
+    /// the view. Shorter touches are taps, so quick taps on buttons in the content
+    /// don't move it. Default is 8.
+    pub touch_drag_threshold: f32,
+    /// Distance in pixels the content moves per pixel of mouse drag or touch motion.
+    /// Default is 1, so the content follows the pointer.
+    pub drag_speed: f32,
+    /// Fraction of the view size scrolled by a page, see `ScrollDistance::Pages`.
+    /// Slightly less than a full page keeps some of the previous content visible.
+    /// Default is 0.9.
+    pub page_fraction: f32,
+    /// Uses the `Interaction` component of the view, maintained by the Bevy UI, to detect
+    /// hovering and pressing. When disabled, `Interaction` is not added to the view and
+    /// the state is resolved from the cursor and touch positions instead, so the view
+    /// does not take part in the UI focus handling.
+    /// It is read once when the `ScrollView` is added.
+    /// Default is true.
+    pub use_interaction: bool,
+    /// Scrolls the view with the arrow keys, PageUp/PageDown and Home/End while it is
+    /// the `ActiveScrollView`, or while it is hovered and no view is active.
+    /// Default is false, so the keys are left to the rest of the app.
+    pub keyboard: bool,
+    /// The mouse wheel scrolls the view while it is the `ActiveScrollView`, wherever
+    /// the pointer is, taking precedence over the hovered views. Useful for keyboard
+    /// and controller driven UIs setting the active view from their focus.
+    /// Default is false, the wheel scrolls the hovered views.
+    pub wheel_when_active: bool,
+    /// Distance in pixels the content moves per line of the horizontal mouse wheel,
+    /// or of the vertical one scrolling the horizontal axis. Negative number inverts
+    /// the horizontal scrolling, zero disables it.
+    /// Default is None, so `ScrollView::scroll_speed` is used on both axes.
+    pub horizontal_scroll_speed: Option<f32>,
+    /// Distance in pixels the content moves per line of the mouse wheel on both axes,
+    /// so the wheel can be tuned without changing the keyboard and gamepad steps, which
+    /// keep using `scroll_speed` and `horizontal_scroll_speed`. Negative number inverts
+    /// the wheel, zero disables the line wheel.
+    /// Default is None, so the wheel uses the `scroll_speed` of each axis.
+    pub line_step: Option<f32>,
+    /// Scales the distance of a wheel line with the length of the content, so crossing
+    /// the content takes about the same number of lines however long it is. One line moves
+    /// the content by the line distance multiplied by `content_size / view_size` on each
+    /// axis, never by less than the line distance itself. Pixel input is not scaled.
+    /// Default is false.
+    pub adaptive_speed: bool,
+    /// Distance in pixels the content moves per pixel of the wheels reporting pixels,
+    /// e.g. precision touchpads. Only the line input is scaled by `scroll_speed`,
+    /// which only sets the direction of the pixel input.
+    /// Default is 1, so the content follows the touchpad.
+    pub pixel_scroll_speed: f32,
+    /// Maximum distance in pixels the mouse wheel moves the content on each axis in a frame,
+    /// so wheels sending huge deltas don't jump across the whole content.
+    /// Default is None, the distance is not limited.
+    pub max_wheel_step: Option<f32>,
+    /// Consumes the input of a view with `ScrollPropagation::Never` also when its content
+    /// fits, so the parent views don't scroll while the pointer is over it, e.g. for
+    /// a short list placed in a scrolling page.
+    /// Default is false, the input over fitting content scrolls the parents.
+    pub consume_when_fitting: bool,
+    /// The vertical mouse wheel scrolls horizontally while Shift is held, in views
+    /// scrolling on the horizontal axis. Views scrolling only vertically ignore Shift.
+    /// Default is true.
+    pub shift_wheel_horizontal: bool,
+    /// Scrolls the view with the right stick and the dpad of the `ScrollGamepad` while it is
+    /// the `ActiveScrollView`, or while it is hovered and no view is active.
+    /// Default is false, so the gamepad is left to the rest of the app.
+    pub gamepad: bool,
+    /// Distance in pixels per second the content moves with the right stick fully deflected.
+    /// Default is 800.
+    pub gamepad_stick_speed: f32,
+}
+
+impl Default for ScrollInput {
+    fn default() -> Self {
+        Self {
+            wheel_axis_fallback: true,
+            wheel_y_scrolls_horizontal: true,
+            invert_wheel: false,
+            invert_drag: false,
+            invert_touch: false,
+            touch_drag_threshold: 8.0,
+            drag_speed: 1.0,
+            page_fraction: 0.9,
+            use_interaction: true,
+            keyboard: false,
+            wheel_when_active: false,
+            horizontal_scroll_speed: None,
+            line_step: None,
+            adaptive_speed: false,
+            pixel_scroll_speed: 1.0,
+            max_wheel_step: None,
+            consume_when_fitting: false,
+            shift_wheel_horizontal: true,
+            gamepad: false,
+            gamepad_stick_speed: 800.0,
+        }
+    }
+}
+
+/// Animation options of the `ScrollView`.
+#[derive(Debug, Clone, Reflect)]
+pub struct ScrollAnimation {
+    /// Approximate time in seconds the content needs to reach the position targeted
+    /// by the mouse wheel. Scrolling again during the animation extends the target.
+    /// Default is 0, which applies the wheel scroll instantly.
+    pub smooth_time: f32,
+    /// Keeps the content moving after a drag or touch is released, slowing down
+    /// by `fling_friction`. Default is false.
+    pub fling: bool,
+    /// Rate per second at which the fling velocity decays, higher values stop sooner.
+    /// Default is 4.
+    pub fling_friction: f32,
+    /// Speed in pixels per second the drag or touch needs at the release to start
+    /// the fling, so slow drags and taps stop where they are released.
+    /// Pressing the view again stops a running fling.
+    /// Default is 100.
+    pub min_fling_velocity: f32,
+    /// When the fling reaches the start or end of the content, the remaining velocity
+    /// moves the content past it and springs it back. Otherwise the fling stops
+    /// at the boundary. Applies only to `BoundaryMode::Clamp`.
+    /// Default is false.
+    pub bounce: bool,
+    /// Lets the content be dragged past its start or end with increasing resistance,
+    /// springing back once released. It also makes the fling bounce, like `bounce`.
+    /// Applies only to `BoundaryMode::Clamp`.
+    /// Default is `Overscroll::None`.
+    pub overscroll: Overscroll,
+}
+
+impl Default for ScrollAnimation {
+    fn default() -> Self {
+        Self {
+            smooth_time: 0.0,
+            fling: false,
+            fling_friction: 4.0,
+            min_fling_velocity: 100.0,
+            bounce: false,
+            overscroll: Overscroll::None,
+        }
+    }
+}
+
+/// Behavior of the `ScrollView` when the content is dragged past its start or end.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+pub enum Overscroll {
+    /// The content stops at the boundary.
+    #[default]
+    None,
+    /// The content follows the drag past the boundary with increasing resistance,
+    /// like the lists on iOS.
+    Elastic {
+        /// Distance in pixels the content can be dragged past the boundary, it is
+        /// approached but never reached.
+        max_px: f32,
+        /// Stiffness of the spring pulling the content back, higher values return faster.
+        stiffness: f32,
+    },
+}
+
+/// Options of the scrollable range of the `ScrollView`.
+#[derive(Debug, Clone, Reflect)]
+pub struct ScrollBounds {
+    /// Extra empty space (leading, trailing) added before and after the content
+    /// along the scroll axis, similar to `contentInset` on iOS.
+    /// It allows the first and last item to be scrolled further into the view.
+    ///
+    /// Like the other lengths of the bounds, it is resolved against the `ScrollView` size
+    /// after the layout: `Val::Percent` is relative to the view size along the scroll axis,
+    /// the viewport units (`Val::Vw`, `Val::Vh`, ...) are relative to the view, which is
+    /// the viewport of the content, and `Val::Auto` is zero.
+    /// Default is no padding.
+    pub content_padding: (Val, Val),
+    /// Distance from the end of the content at which `ScrollReachedEnd` is sent,
+    /// so more content can be loaded before the user reaches the very end.
+    /// Default is `None`, which disables the event.
+    pub end_threshold: Option<Val>,
+    /// Time in seconds after which `ScrollReachedEnd` can be sent again while the user
+    /// stays near the end and the content has not grown. Leaving the end area and coming
+    /// back always sends the event again.
+    /// Default is 1 second.
+    pub load_more_cooldown: f32,
+    /// What happens when the content is scrolled past its start or end.
+    /// Default is `BoundaryMode::Clamp`.
+    pub boundary_mode: BoundaryMode,
+    /// Space in pixels at the right and bottom edge of the view which is not part
+    /// of the viewport, so the content can be scrolled out from under it.
+    /// It is set by the `Scrollbar` with `ScrollbarLayout::Inset`.
+    /// Default is zero.
+    pub viewport_inset: Vec2,
+}
+
+impl Default for ScrollBounds {
+    fn default() -> Self {
+        Self {
+            content_padding: (Val::Px(0.0), Val::Px(0.0)),
+            end_threshold: None,
+            load_more_cooldown: 1.0,
+            boundary_mode: BoundaryMode::Clamp,
+            viewport_inset: Vec2::ZERO,
+        }
+    }
+}
+
+/// Behavior of the `ScrollView` at the start and end of the content.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum BoundaryMode {
+    /// The offset stays in the scrollable range, scrolling stops at the ends.
+    #[default]
+    Clamp,
+    /// The offset is not limited, the content can be scrolled into empty space.
+    /// Useful for infinite canvases which apply their own bounds.
+    ///
+    /// The view never reaches a boundary, so it applies the whole input and consumes it
+    /// even with `ScrollPropagation::AtBoundary`, the parent views don't scroll. To allow only
+    /// a limited distance past the edges, use `Clamp` with `ScrollBounds::content_padding`.
+    Free,
+    /// The offset wraps around the content size, for looping carousels.
+    /// The content should repeat its first items after the last ones, so there is
+    /// no visible jump when the offset wraps.
+    Wrap,
+}
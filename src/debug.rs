@@ -0,0 +1,106 @@
+use bevy::{prelude::*, utils::HashMap, window::PrimaryWindow};
+
+use crate::{ScrollEdge, ScrollMetrics, ScrollView, ScrollableContent};
+
+/// Resource enabling the debug drawing of the scroll views, available with the `debug`
+/// feature. While it is present, the rect of each view and of its content are drawn
+/// with `Gizmos` and the edges the content is pinned against are drawn in
+/// `boundary_color`. Changes of the reached edges are logged at the debug level
+/// together with the offset.
+///
+/// The gizmos are drawn in world space, they line up with the UI for a `Camera2dBundle`
+/// at the origin rendering to the primary window.
+#[derive(Resource, Debug, Clone, Reflect)]
+pub struct ScrollViewDebug {
+    /// Color of the view rect. Default is green.
+    pub view_color: Color,
+    /// Color of the content rect. Default is blue.
+    pub content_color: Color,
+    /// Color of the reached edges of the view. Default is red.
+    pub boundary_color: Color,
+}
+
+impl Default for ScrollViewDebug {
+    fn default() -> Self {
+        Self {
+            view_color: Color::srgb(0.0, 1.0, 0.0),
+            content_color: Color::srgb(0.0, 0.5, 1.0),
+            boundary_color: Color::srgb(1.0, 0.0, 0.0),
+        }
+    }
+}
+
+/// Draws the views and their content, it runs only while the `ScrollViewDebug`
+/// resource is present, so apps without `GizmoPlugin` can enable the feature.
+pub fn draw_scroll_debug(
+    debug: Res<ScrollViewDebug>,
+    mut gizmos: Gizmos,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    views: Query<(
+        Entity,
+        &ScrollView,
+        &ScrollMetrics,
+        &Node,
+        &GlobalTransform,
+        &Children,
+    )>,
+    content_q: Query<(&Node, &GlobalTransform), With<ScrollableContent>>,
+    mut reached: Local<HashMap<Entity, Vec<ScrollEdge>>>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    // The UI origin is the top left corner of the window with y pointing down.
+    let half_size = window.size() / 2.0;
+    let to_world = |point: Vec2| {
+        let point = point * ui_scale.0;
+        Vec2::new(point.x - half_size.x, half_size.y - point.y)
+    };
+
+    reached.retain(|entity, _| views.contains(*entity));
+    for (entity, scroll_view, metrics, node, transform, children) in views.iter() {
+        let rect = node.logical_rect(transform);
+        let (min, max) = (to_world(rect.min), to_world(rect.max));
+        gizmos.rect_2d((min + max) / 2.0, 0.0, (max - min).abs(), debug.view_color);
+        for (content_node, content_transform) in
+            children.iter().filter_map(|&c| content_q.get(c).ok())
+        {
+            let content = content_node.logical_rect(content_transform);
+            let (min, max) = (to_world(content.min), to_world(content.max));
+            gizmos.rect_2d(
+                (min + max) / 2.0,
+                0.0,
+                (max - min).abs(),
+                debug.content_color,
+            );
+        }
+
+        let edges: Vec<ScrollEdge> = ScrollEdge::ALL
+            .into_iter()
+            .filter(|edge| {
+                edge.is_reached(
+                    metrics.offset,
+                    metrics.max_scroll,
+                    scroll_view.right_to_left,
+                )
+            })
+            .collect();
+        for edge in &edges {
+            let (start, end) = match edge {
+                ScrollEdge::Top => (rect.min, Vec2::new(rect.max.x, rect.min.y)),
+                ScrollEdge::Bottom => (Vec2::new(rect.min.x, rect.max.y), rect.max),
+                ScrollEdge::Left => (rect.min, Vec2::new(rect.min.x, rect.max.y)),
+                ScrollEdge::Right => (Vec2::new(rect.max.x, rect.min.y), rect.max),
+            };
+            gizmos.line_2d(to_world(start), to_world(end), debug.boundary_color);
+        }
+        if reached.get(&entity) != Some(&edges) {
+            debug!(
+                "ScrollView {entity:?} at offset {} of {} reached {edges:?}",
+                metrics.offset, metrics.max_scroll
+            );
+            reached.insert(entity, edges);
+        }
+    }
+}
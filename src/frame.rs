@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+
+use crate::{ScrollAxis, ScrollView};
+
+/// Component for a node wrapping a `ScrollView` together with fixed `ScrollHeader` and
+/// `ScrollFooter` nodes, which stay in place while the content scrolls:
+/// ```text
+/// ScrollFrame
+/// ├── ScrollHeader
+/// ├── ScrollView
+/// │   └── ScrollableContent
+/// └── ScrollFooter
+/// ```
+/// The frame lays out its children along the scroll axis of the view and the view fills
+/// the space left by the header and footer, so the viewport used for the scroll bounds
+/// is only the region between them. Children are laid out in their order, so the header
+/// should be the first child and the footer the last one.
+#[derive(Component, Debug, Reflect, Default, Clone, Copy)]
+pub struct ScrollFrame;
+
+/// Marker of the child of the `ScrollFrame` pinned before the scrollable region.
+#[derive(Component, Debug, Reflect, Default, Clone, Copy)]
+pub struct ScrollHeader;
+
+/// Marker of the child of the `ScrollFrame` pinned after the scrollable region.
+#[derive(Component, Debug, Reflect, Default, Clone, Copy)]
+pub struct ScrollFooter;
+
+/// Sets up the layout of newly added `ScrollFrame` nodes and their children.
+pub fn create_scroll_frame(
+    mut frames: Query<
+        (&Children, &mut Style),
+        (
+            With<ScrollFrame>,
+            Or<(Added<ScrollFrame>, Changed<Children>)>,
+            Without<ScrollView>,
+        ),
+    >,
+    mut views: Query<(&ScrollView, &mut Style), Without<ScrollFrame>>,
+    mut pinned: Query<
+        &mut Style,
+        (
+            Or<(With<ScrollHeader>, With<ScrollFooter>)>,
+            Without<ScrollView>,
+            Without<ScrollFrame>,
+        ),
+    >,
+) {
+    for (children, mut style) in frames.iter_mut() {
+        let axis = children
+            .iter()
+            .find_map(|&child| views.get(child).ok())
+            .map_or(ScrollAxis::Vertical, |(scroll_view, _)| scroll_view.axis);
+        style.flex_direction = if axis == ScrollAxis::Horizontal {
+            FlexDirection::Row
+        } else {
+            FlexDirection::Column
+        };
+        style.overflow = Overflow::clip();
+        for &child in children.iter() {
+            if let Ok(mut style) = pinned.get_mut(child) {
+                style.flex_shrink = 0.0;
+            }
+            // The view takes the remaining space instead of growing with its content.
+            if let Ok((_, mut style)) = views.get_mut(child) {
+                style.flex_grow = 1.0;
+                style.flex_shrink = 1.0;
+                style.flex_basis = Val::Px(0.0);
+                style.min_width = Val::Px(0.0);
+                style.min_height = Val::Px(0.0);
+            }
+        }
+    }
+}
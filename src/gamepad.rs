@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+
+use crate::{
+    handle_scroll_for_view, handle_smooth_scroll_for_view, keyboard::input_target,
+    pointer::is_visible, ActiveScrollView, ScrollInteraction, ScrollView, ScrollableContent,
+    SmoothScroll,
+};
+
+/// Deflection of the stick ignored by the gamepad scrolling, so resting sticks
+/// don't drift the view.
+pub const GAMEPAD_DEAD_ZONE: f32 = 0.15;
+
+/// Resource selecting the gamepad scrolling the views, `None` uses the first connected one.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub struct ScrollGamepad(pub Option<Gamepad>);
+
+/// Scrolls the `ActiveScrollView`, or the innermost hovered view when there is no active one,
+/// with the gamepad: the right stick scrolls continuously with the deflection, up to
+/// `ScrollInput::gamepad_stick_speed` pixels per second, and each press of the dpad
+/// scrolls by `scroll_speed` pixels like the arrow keys.
+#[allow(clippy::too_many_arguments)]
+pub fn input_gamepad(
+    mut commands: Commands,
+    time: Res<Time>,
+    selected: Res<ScrollGamepad>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    buttons: Res<ButtonInput<GamepadButton>>,
+    active: Res<ActiveScrollView>,
+    views: Query<(
+        Entity,
+        &ScrollView,
+        &Node,
+        &Children,
+        &ScrollInteraction,
+        Option<&InheritedVisibility>,
+    )>,
+    parents: Query<&Parent>,
+    mut content_q: Query<(&mut ScrollableContent, &Node)>,
+    mut smooth_q: Query<&mut SmoothScroll>,
+) {
+    let Some(gamepad) = selected.0.or_else(|| gamepads.iter().next()) else {
+        return;
+    };
+    let target = input_target(&active, views.iter().map(|(e, .., i, _)| (e, i)), &parents);
+    let Some((_, scroll_view, node, children, ..)) = target
+        .and_then(|e| views.get(e).ok())
+        .filter(|(_, _, node, _, _, visibility)| is_visible(*visibility, node))
+    else {
+        return;
+    };
+    if !scroll_view.enabled || !scroll_view.input.gamepad {
+        return;
+    }
+
+    let axis = |axis_type| {
+        axes.get(GamepadAxis::new(gamepad, axis_type))
+            .unwrap_or(0.0)
+    };
+    let stick = dead_zone(Vec2::new(
+        axis(GamepadAxisType::RightStickX),
+        axis(GamepadAxisType::RightStickY),
+    ));
+    if stick != Vec2::ZERO {
+        // Pushing the stick up reveals the content above, so the content moves down.
+        let delta = Vec2::new(-stick.x, stick.y)
+            * scroll_view.input.gamepad_stick_speed
+            * time.delta_seconds();
+        let delta = scroll_view.physical_delta(delta);
+        handle_scroll_for_view(children, scroll_view, node, delta, &mut content_q);
+    }
+
+    let step = scroll_view.axis_scroll_speed().abs();
+    let pressed = |button_type| buttons.just_pressed(GamepadButton::new(gamepad, button_type));
+    let mut delta = Vec2::ZERO;
+    if pressed(GamepadButtonType::DPadUp) {
+        delta.y += step.y;
+    }
+    if pressed(GamepadButtonType::DPadDown) {
+        delta.y -= step.y;
+    }
+    if pressed(GamepadButtonType::DPadLeft) {
+        delta.x += step.x;
+    }
+    if pressed(GamepadButtonType::DPadRight) {
+        delta.x -= step.x;
+    }
+    if delta == Vec2::ZERO {
+        return;
+    }
+    let delta = scroll_view.physical_delta(delta);
+    if scroll_view.animation.smooth_time > 0.0 {
+        handle_smooth_scroll_for_view(
+            &mut commands,
+            children,
+            scroll_view,
+            node,
+            delta,
+            &content_q,
+            &mut smooth_q,
+        );
+    } else {
+        handle_scroll_for_view(children, scroll_view, node, delta, &mut content_q);
+    }
+}
+
+/// Removes the `GAMEPAD_DEAD_ZONE` from the stick deflection, rescaling the rest
+/// so the scrolling starts smoothly from zero at its edge.
+fn dead_zone(stick: Vec2) -> Vec2 {
+    let length = stick.length();
+    if length <= GAMEPAD_DEAD_ZONE {
+        return Vec2::ZERO;
+    }
+    let scaled = ((length - GAMEPAD_DEAD_ZONE) / (1.0 - GAMEPAD_DEAD_ZONE)).min(1.0);
+    stick * scaled / length
+}
@@ -0,0 +1,72 @@
+use bevy::{
+    ecs::system::SystemParam, hierarchy::HierarchyQueryExt, prelude::*, window::PrimaryWindow,
+};
+
+use crate::{ScrollView, ScrollableContent};
+
+/// `SystemParam` resolving which `ScrollView` is under a given point, e.g. for tooltips
+/// or context menus placed over scrollable content.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_scroll_view::*;
+///
+/// fn show_context_menu(hit_test: ScrollViewHitTest) {
+///     if let Some((view, content_pos)) = hit_test.cursor() {
+///         info!("Cursor is over {view:?} at {content_pos} in its content");
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct ScrollViewHitTest<'w, 's> {
+    views: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Node,
+            &'static GlobalTransform,
+            &'static Children,
+            Option<&'static CalculatedClip>,
+        ),
+        With<ScrollView>,
+    >,
+    content_q: Query<'w, 's, (&'static Node, &'static GlobalTransform), With<ScrollableContent>>,
+    parents: Query<'w, 's, &'static Parent>,
+    windows: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    ui_scale: Res<'w, UiScale>,
+}
+
+impl<'w, 's> ScrollViewHitTest<'w, 's> {
+    /// Returns the innermost `ScrollView` containing the `point` given in logical UI
+    /// coordinates, together with the point position relative to the top left corner
+    /// of its `ScrollableContent`.
+    pub fn hit_test(&self, point: Vec2) -> Option<(Entity, Vec2)> {
+        let mut innermost: Option<(Entity, usize, &Children)> = None;
+        for (entity, node, transform, children, clip) in self.views.iter() {
+            let rect = node.logical_rect(transform);
+            let visible_rect = clip.map_or(rect, |clip| rect.intersect(clip.clip));
+            if !visible_rect.contains(point) {
+                continue;
+            }
+            let depth = self.parents.iter_ancestors(entity).count();
+            if innermost.is_none_or(|(_, innermost_depth, _)| depth > innermost_depth) {
+                innermost = Some((entity, depth, children));
+            }
+        }
+
+        let (entity, _, children) = innermost?;
+        let (content_node, content_transform) = children
+            .iter()
+            .find_map(|&child| self.content_q.get(child).ok())?;
+        let content_rect = content_node.logical_rect(content_transform);
+        Some((entity, point - content_rect.min))
+    }
+
+    /// Same as `hit_test`, using the cursor position in the primary window.
+    pub fn cursor(&self) -> Option<(Entity, Vec2)> {
+        let cursor = self.windows.get_single().ok()?.cursor_position()?;
+        self.hit_test(cursor / self.ui_scale.0)
+    }
+}
@@ -0,0 +1,171 @@
+use bevy::{
+    hierarchy::HierarchyQueryExt,
+    input::{keyboard::KeyboardInput, ButtonState},
+    prelude::*,
+};
+
+use crate::{
+    content_child, handle_scroll_for_view, handle_smooth_scroll_for_view, pointer::is_visible,
+    ScrollDistance, ScrollInteraction, ScrollView, ScrollableContent, SmoothScroll,
+};
+
+/// Resource holding the `ScrollView` which receives the keyboard input.
+///
+/// It is set to the innermost view pressed with the mouse or touch and cleared when
+/// the press lands outside of all views, so the keyboard keeps scrolling the view after
+/// the cursor leaves it. It can be also set manually, e.g. by the focus handling
+/// of a keyboard or controller driven UI. Views with `ScrollInput::wheel_when_active`
+/// receive also the mouse wheel while they are active.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub struct ActiveScrollView(pub Option<Entity>);
+
+/// Sets the `ActiveScrollView` to the view pressed this frame.
+pub fn update_active_scroll_view(
+    mut active: ResMut<ActiveScrollView>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    views: Query<(Entity, &ScrollInteraction)>,
+    parents: Query<&Parent>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) && touches.iter_just_pressed().next().is_none() {
+        return;
+    }
+    let pressed = views
+        .iter()
+        .filter(|(_, interaction)| interaction.0 == Interaction::Pressed)
+        .max_by_key(|(entity, _)| parents.iter_ancestors(*entity).count())
+        .map(|(entity, _)| entity);
+    if active.0 != pressed {
+        active.0 = pressed;
+    }
+}
+
+/// Scrolls the `ActiveScrollView`, or the innermost hovered view when there is no active one,
+/// with the keyboard: the arrow keys scroll by `scroll_speed` pixels like a line of the mouse
+/// wheel, PageUp/PageDown by a page and Home/End jump to the start and end of the content.
+/// Held keys repeat with the key repeat of the system.
+pub fn input_keyboard(
+    mut commands: Commands,
+    mut keyboard_evr: EventReader<KeyboardInput>,
+    active: Res<ActiveScrollView>,
+    views: Query<(
+        Entity,
+        &ScrollView,
+        &Node,
+        &Children,
+        &ScrollInteraction,
+        Option<&InheritedVisibility>,
+    )>,
+    parents: Query<&Parent>,
+    mut content_q: Query<(&mut ScrollableContent, &Node)>,
+    mut smooth_q: Query<&mut SmoothScroll>,
+) {
+    let target = input_target(&active, views.iter().map(|(e, .., i, _)| (e, i)), &parents);
+    let Some((_, scroll_view, node, children, ..)) = target
+        .and_then(|e| views.get(e).ok())
+        .filter(|(_, _, node, _, _, visibility)| is_visible(*visibility, node))
+    else {
+        keyboard_evr.clear();
+        return;
+    };
+    for ev in keyboard_evr.read() {
+        if ev.state != ButtonState::Pressed || !scroll_view.enabled || !scroll_view.input.keyboard {
+            continue;
+        }
+        let step = scroll_view.axis_scroll_speed().abs();
+        let distance = match ev.key_code {
+            KeyCode::ArrowUp => ScrollDistance::Pixels(Vec2::new(0.0, -step.y)),
+            KeyCode::ArrowDown => ScrollDistance::Pixels(Vec2::new(0.0, step.y)),
+            // The arrows move in the physical directions, also in right-to-left views.
+            KeyCode::ArrowLeft => {
+                ScrollDistance::Pixels(scroll_view.physical_delta(Vec2::new(-step.x, 0.0)))
+            }
+            KeyCode::ArrowRight => {
+                ScrollDistance::Pixels(scroll_view.physical_delta(Vec2::new(step.x, 0.0)))
+            }
+            KeyCode::PageUp => ScrollDistance::Pages(-1.0),
+            KeyCode::PageDown => ScrollDistance::Pages(1.0),
+            KeyCode::Home | KeyCode::End => {
+                let end = ev.key_code == KeyCode::End;
+                jump_to_edge(
+                    &mut commands,
+                    children,
+                    scroll_view,
+                    node,
+                    end,
+                    &mut content_q,
+                );
+                continue;
+            }
+            _ => continue,
+        };
+        // Scrolling towards the end moves the content in the negative direction.
+        let delta = -distance.to_pixels(scroll_view, node.size());
+        if scroll_view.animation.smooth_time > 0.0 {
+            handle_smooth_scroll_for_view(
+                &mut commands,
+                children,
+                scroll_view,
+                node,
+                delta,
+                &content_q,
+                &mut smooth_q,
+            );
+        } else {
+            handle_scroll_for_view(children, scroll_view, node, delta, &mut content_q);
+        }
+    }
+}
+
+/// Returns the view receiving the keyboard or gamepad input, the `ActiveScrollView`
+/// or the innermost hovered view of `views`.
+pub(crate) fn input_target<'a>(
+    active: &ActiveScrollView,
+    views: impl Iterator<Item = (Entity, &'a ScrollInteraction)>,
+    parents: &Query<&Parent>,
+) -> Option<Entity> {
+    active.0.or_else(|| {
+        views
+            .filter(|(_, interaction)| interaction.0 == Interaction::Hovered)
+            .max_by_key(|(entity, _)| parents.iter_ancestors(*entity).count())
+            .map(|(entity, _)| entity)
+    })
+}
+
+/// Moves the content to its start, or end when `end` is true, on the axes of the view.
+fn jump_to_edge(
+    commands: &mut Commands,
+    children: &Children,
+    scroll_view: &ScrollView,
+    node: &Node,
+    end: bool,
+    content_q: &mut Query<(&mut ScrollableContent, &Node)>,
+) {
+    let Some(child) = content_child(children, content_q) else {
+        return;
+    };
+    let Ok((mut scroll, content_node)) = content_q.get_mut(child) else {
+        return;
+    };
+    let edge = if end {
+        -scroll_view.max_scroll(content_node.size(), node.size())
+    } else {
+        Vec2::ZERO
+    };
+    let mut target = Vec2::new(scroll.pos_x, scroll.pos_y);
+    if scroll_view.axis.scrolls_horizontally() {
+        target.x = edge.x;
+    }
+    if scroll_view.axis.scrolls_vertically() {
+        target.y = edge.y;
+    }
+    if scroll_view.animation.smooth_time > 0.0 {
+        commands
+            .entity(child)
+            .insert(SmoothScroll::to(target, scroll_view.animation.smooth_time));
+    } else {
+        scroll.pos_x = target.x;
+        scroll.pos_y = target.y;
+        commands.entity(child).remove::<SmoothScroll>();
+    }
+}
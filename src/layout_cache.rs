@@ -0,0 +1,283 @@
+use bevy::prelude::*;
+
+use crate::{content_child, ScrollAnchor, ScrollAxis, ScrollView, ScrollableContent, SmoothScroll};
+
+/// Component added to the `ScrollableContent` entity, caching the offset and size of each
+/// of its children along the scroll axis (vertical for `ScrollAxis::Both`).
+///
+/// It is rebuilt when the children or their layout change, so it can be used to quickly
+/// find which child is at given offset, or what is the offset of given child,
+/// even when the children have different sizes.
+#[derive(Component, Debug, Reflect, Default, Clone)]
+pub struct ScrollLayoutCache {
+    /// True when the offsets are measured along the horizontal axis.
+    pub horizontal: bool,
+    /// Children of the content sorted by their offset.
+    pub entries: Vec<ScrollLayoutEntry>,
+}
+
+/// Position of a single child of the `ScrollableContent`, see `ScrollLayoutCache`.
+#[derive(Debug, Reflect, Clone, Copy, PartialEq)]
+pub struct ScrollLayoutEntry {
+    /// The child entity.
+    pub entity: Entity,
+    /// Distance from the start of the content to the start of the child.
+    pub offset: f32,
+    /// Size of the child along the scroll axis.
+    pub size: f32,
+}
+
+impl ScrollLayoutCache {
+    /// Number of cached children.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the content has no children.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the child at given index.
+    pub fn entity(&self, index: usize) -> Option<Entity> {
+        self.entries.get(index).map(|e| e.entity)
+    }
+
+    /// Returns the index of the given child.
+    pub fn index_of(&self, entity: Entity) -> Option<usize> {
+        self.entries.iter().position(|e| e.entity == entity)
+    }
+
+    /// Returns the distance from the start of the content to the start of the child at given index.
+    pub fn offset_of(&self, index: usize) -> Option<f32> {
+        self.entries.get(index).map(|e| e.offset)
+    }
+
+    /// Returns the size along the scroll axis of the child at given index.
+    pub fn size_of(&self, index: usize) -> Option<f32> {
+        self.entries.get(index).map(|e| e.size)
+    }
+
+    /// Returns the index of the child placed at given distance from the start of the content.
+    /// Offsets in the gaps between children return the next child.
+    pub fn index_at(&self, offset: f32) -> Option<usize> {
+        let index = self
+            .entries
+            .partition_point(|e| e.offset + e.size <= offset);
+        (index < self.entries.len()).then_some(index)
+    }
+}
+
+/// Event scrolling the given `ScrollView` to a fractional child index of its content,
+/// e.g. `2.5` is halfway between the third and fourth child, useful for timelines
+/// and filmstrips. The offset and size are interpolated between the adjacent children
+/// from the `ScrollLayoutCache`, so the event is ignored until the cache is built.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ScrollToIndex {
+    /// `ScrollView` entity, not the `ScrollableContent`.
+    pub view: Entity,
+    /// Index of the child, clamped to the existing children.
+    pub index: f32,
+    /// Alignment of the interpolated child within the viewport.
+    pub align: ScrollAnchor,
+    /// Approximate time in seconds of the animation, zero jumps instantly.
+    pub smooth_time: f32,
+}
+
+impl ScrollLayoutCache {
+    /// Returns the offset and size of the child at the fractional `index`,
+    /// interpolated between the adjacent children.
+    pub fn interpolate(&self, index: f32) -> Option<(f32, f32)> {
+        let last = self.entries.len().checked_sub(1)?;
+        let index = index.clamp(0.0, last as f32);
+        let first = self.entries[index.floor() as usize];
+        let second = self.entries[(index.ceil() as usize).min(last)];
+        let t = index.fract();
+        Some((
+            first.offset + (second.offset - first.offset) * t,
+            first.size + (second.size - first.size) * t,
+        ))
+    }
+}
+
+/// Handles the `ScrollToIndex` events.
+pub fn scroll_to_index(
+    mut commands: Commands,
+    mut events: EventReader<ScrollToIndex>,
+    views: Query<(&ScrollView, &Node, &Children)>,
+    mut content_q: Query<(&mut ScrollableContent, &Node, &ScrollLayoutCache)>,
+) {
+    for ev in events.read() {
+        let Ok((scroll_view, node, children)) = views.get(ev.view) else {
+            continue;
+        };
+        let Some(child) = content_child(children, &content_q) else {
+            continue;
+        };
+        let Ok((mut scroll, content_node, cache)) = content_q.get_mut(child) else {
+            continue;
+        };
+        let Some((offset, size)) = cache.interpolate(ev.index) else {
+            continue;
+        };
+        let max_scroll = scroll_view.max_scroll(content_node.size(), node.size());
+        let leading = scroll_view.padding(node.size()).0;
+        let mut target = Vec2::new(scroll.pos_x, scroll.pos_y);
+        if cache.horizontal {
+            target.x = ev
+                .align
+                .offset_for(offset + leading.x, size, node.size().x)
+                .clamp(-max_scroll.x, 0.0);
+        } else {
+            target.y = ev
+                .align
+                .offset_for(offset + leading.y, size, node.size().y)
+                .clamp(-max_scroll.y, 0.0);
+        }
+        if ev.smooth_time > 0.0 {
+            commands
+                .entity(child)
+                .insert(SmoothScroll::to(target, ev.smooth_time));
+        } else {
+            scroll.pos_x = target.x;
+            scroll.pos_y = target.y;
+            commands.entity(child).remove::<SmoothScroll>();
+        }
+    }
+}
+
+/// Opt-in component for the `ScrollView` entity, listing the children of its
+/// `ScrollableContent` currently visible in the viewport, e.g. to animate items
+/// as they enter the view. It is updated when the content scrolls or its layout changes.
+#[derive(Component, Debug, Reflect, Default, Clone)]
+pub struct VisibleChildren {
+    /// Visible children, sorted by their offset.
+    pub entities: Vec<Entity>,
+    /// Fraction of the child size along the scroll axis that has to be inside
+    /// the viewport for the child to be listed. Default is 0, so any partially
+    /// visible child is listed, 1 lists only fully visible children.
+    pub threshold: f32,
+}
+
+impl VisibleChildren {
+    /// Creates the component listing children with at least `threshold` of their size visible.
+    pub fn with_threshold(threshold: f32) -> Self {
+        Self {
+            entities: Vec::new(),
+            threshold,
+        }
+    }
+}
+
+/// Updates the `VisibleChildren` of the views.
+pub fn update_visible_children(
+    mut views: Query<(&ScrollView, Ref<Node>, &Children, &mut VisibleChildren)>,
+    content_q: Query<(Ref<ScrollableContent>, Ref<ScrollLayoutCache>)>,
+) {
+    for (scroll_view, node, children, mut visible) in views.iter_mut() {
+        for &child in children.iter() {
+            let Ok((scroll, cache)) = content_q.get(child) else {
+                continue;
+            };
+            if !node.is_changed()
+                && !scroll.is_changed()
+                && !cache.is_changed()
+                && !visible.is_changed()
+            {
+                continue;
+            }
+            let leading = scroll_view.padding(node.size()).0;
+            let (pos, leading, viewport) = if cache.horizontal {
+                (scroll.pos_x, leading.x, node.size().x)
+            } else {
+                (scroll.pos_y, leading.y, node.size().y)
+            };
+            // Visible part of the content, measured from its start.
+            let start = -pos - leading;
+            let end = start + viewport;
+            let threshold = visible.threshold.clamp(0.0, 1.0);
+            let entities: Vec<_> = cache
+                .entries
+                .iter()
+                .filter(|e| {
+                    let overlap = (e.offset + e.size).min(end) - e.offset.max(start);
+                    overlap > 0.0 && overlap >= e.size * threshold
+                })
+                .map(|e| e.entity)
+                .collect();
+            if visible.entities != entities {
+                visible.entities = entities;
+            }
+        }
+    }
+}
+
+/// Builds and updates the `ScrollLayoutCache` of the `ScrollableContent` entities.
+pub fn update_layout_cache(
+    mut commands: Commands,
+    views: Query<&ScrollView>,
+    mut content_q: Query<
+        (
+            Entity,
+            &Parent,
+            Ref<Children>,
+            Ref<Node>,
+            Option<&mut ScrollLayoutCache>,
+        ),
+        With<ScrollableContent>,
+    >,
+    children_q: Query<(Ref<Node>, Ref<Transform>)>,
+) {
+    for (entity, parent, children, node, cache) in content_q.iter_mut() {
+        let Ok(scroll_view) = views.get(parent.get()) else {
+            continue;
+        };
+        let horizontal = scroll_view.axis == ScrollAxis::Horizontal;
+        let dirty = cache.as_ref().is_none_or(|c| c.horizontal != horizontal)
+            || children.is_changed()
+            || node.is_changed()
+            || children.iter().any(|&child| {
+                children_q
+                    .get(child)
+                    .is_ok_and(|(node, transform)| node.is_changed() || transform.is_changed())
+            });
+        if !dirty {
+            continue;
+        }
+
+        // Layout places the `Transform` of the child at its center, relative to the center
+        // of the content node.
+        let half_content = node.size() / 2.0;
+        let mut entries: Vec<_> = children
+            .iter()
+            .filter_map(|&child| {
+                let (child_node, transform) = children_q.get(child).ok()?;
+                let physical =
+                    transform.translation.truncate() + half_content - child_node.size() / 2.0;
+                let start = scroll_view.child_offset(physical, child_node.size(), node.size());
+                let (offset, size) = if horizontal {
+                    (start.x, child_node.size().x)
+                } else {
+                    (start.y, child_node.size().y)
+                };
+                Some(ScrollLayoutEntry {
+                    entity: child,
+                    offset,
+                    size,
+                })
+            })
+            .collect();
+        entries.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+
+        let new_cache = ScrollLayoutCache {
+            horizontal,
+            entries,
+        };
+        match cache {
+            Some(mut cache) => *cache = new_cache,
+            None => {
+                commands.entity(entity).insert(new_cache);
+            }
+        }
+    }
+}
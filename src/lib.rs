@@ -1,14 +1,57 @@
 #![doc = include_str!("../README.md")]
+// Bevy system parameters are commonly complex queries.
+#![allow(clippy::type_complexity)]
 
 use bevy::{
+    ecs::query::{QueryData, QueryFilter},
+    hierarchy::HierarchyQueryExt,
     input::mouse::{MouseMotion, MouseWheel},
     prelude::*,
+    transform::TransformSystem,
+    ui::UiSystem,
+    window::PrimaryWindow,
 };
 
+#[cfg(feature = "accessibility")]
+mod accessibility;
+#[cfg(feature = "accessibility")]
+pub use accessibility::update_accessibility_node;
+mod auto_scroll;
+mod config;
+#[cfg(feature = "debug")]
+mod debug;
+mod frame;
+mod gamepad;
+mod hit_test;
+mod keyboard;
+mod layout_cache;
+mod momentum;
+mod pointer;
+mod scrollbar;
+mod snap;
+mod snapshot;
+mod spawn;
+
+pub use auto_scroll::*;
+pub use config::*;
+#[cfg(feature = "debug")]
+pub use debug::*;
+pub use frame::*;
+pub use gamepad::*;
+pub use hit_test::*;
+pub use keyboard::*;
+pub use layout_cache::*;
+pub use momentum::{fling_scroll, Fling};
+pub use pointer::{update_scroll_interaction, ScrollInteraction};
+pub use scrollbar::*;
+pub use snap::*;
+pub use snapshot::*;
+pub use spawn::*;
+
 /// A `Plugin` providing the systems and components required to make a ScrollView work.
 ///
 /// # Example
-/// ```
+/// ```no_run
 /// use bevy::prelude::*;
 /// use bevy_simple_scroll_view::*;
 ///
@@ -16,51 +59,470 @@ use bevy::{
 ///     .add_plugins((DefaultPlugins,ScrollViewPlugin))
 ///     .run();
 /// ```
+///
+/// All the systems added by the plugin are public, so instead of the plugin they can be
+/// added individually, e.g. to replace the drag handling with a custom one. In that case
+/// the types and events used by the systems have to be registered manually and the systems
+/// should keep the order in which the plugin runs them, see its `build` method.
 pub struct ScrollViewPlugin;
 
+/// Sets of the systems the `ScrollViewPlugin` adds to `Update`, running in the order
+/// of the variants. User systems can be ordered relative to them, e.g. a progress bar
+/// reading the `ScrollableContent` should run `.after(ScrollSystem::Update)`.
+///
+/// The offset of views with `ScrollView::transform_scroll` is applied later,
+/// in `PostUpdate` after the UI layout.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollSystem {
+    /// Setup of new views and scrollbars, and the pointer, keyboard and gamepad input.
+    Input,
+    /// Handling of the `ScrollTo`, `ScrollBy` and other command events.
+    Events,
+    /// Animations, bounds, metrics and events of the offset, applied to the content style.
+    Update,
+}
+
 impl Plugin for ScrollViewPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<ScrollView>()
             .register_type::<ScrollableContent>()
-            .add_systems(
+            .register_type::<ScrollInput>()
+            .register_type::<ScrollAnimation>()
+            .register_type::<ScrollBounds>()
+            .register_type::<SmoothScroll>()
+            .register_type::<ScrollMomentum>()
+            .register_type::<ScrollLayoutCache>()
+            .register_type::<VisibleChildren>()
+            .register_type::<ScrollMetrics>()
+            .register_type::<StickToBottom>()
+            .register_type::<ScrollAnchoring>()
+            .register_type::<Scrollbar>()
+            .register_type::<ScrollbarTrack>()
+            .register_type::<ScrollbarThumb>()
+            .register_type::<ScrollbarCorner>()
+            .register_type::<ScrollSnap>()
+            .register_type::<AutoScroll>()
+            .register_type::<EdgeAutoScroll>()
+            .register_type::<EdgeAutoScrollActive>()
+            .register_type::<ActiveScrollView>()
+            .register_type::<ScrollGamepad>()
+            .register_type::<ScrollFrame>()
+            .register_type::<ScrollHeader>()
+            .register_type::<ScrollFooter>()
+            .register_type::<ScrollSnapshot>()
+            .add_event::<ScrollToChild>()
+            .add_event::<ScrollToTop>()
+            .add_event::<ScrollBy>()
+            .add_event::<ScrollTo>()
+            .add_event::<ScrollToIndex>()
+            .add_event::<ScrollConsumed>()
+            .add_event::<ScrollReachedEnd>()
+            .add_event::<ScrollChanged>()
+            .add_event::<ScrollBoundary>()
+            .add_event::<RestoreScroll>()
+            .init_resource::<ActiveScrollView>()
+            .init_resource::<ScrollGamepad>()
+            .init_resource::<EdgeAutoScrollActive>()
+            .configure_sets(
                 Update,
                 (
-                    create_scroll_view,
-                    input_mouse_pressed_move,
-                    input_touch_pressed_move,
-                    scroll_events,
-                    scroll_update,
+                    ScrollSystem::Input,
+                    ScrollSystem::Events,
+                    ScrollSystem::Update,
                 )
                     .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    (
+                        create_scroll_view,
+                        frame::create_scroll_frame,
+                        warn_nested_content,
+                        pointer::update_scroll_interaction,
+                        keyboard::update_active_scroll_view,
+                        scrollbar::spawn_scrollbar,
+                        scrollbar::update_scrollbar_layout,
+                        scrollbar::scrollbar_thumb_drag,
+                        scrollbar::scrollbar_track_click,
+                        input_mouse_pressed_move,
+                        input_touch_pressed_move,
+                        keyboard::input_keyboard,
+                        gamepad::input_gamepad,
+                        auto_scroll::edge_auto_scroll,
+                        scroll_events,
+                    )
+                        .chain()
+                        .in_set(ScrollSystem::Input),
+                    (
+                        scroll_to_child,
+                        scroll_to_top,
+                        scroll_by,
+                        scroll_to,
+                        layout_cache::scroll_to_index,
+                        snapshot::restore_scroll,
+                    )
+                        .chain()
+                        .in_set(ScrollSystem::Events),
+                    (
+                        smooth_scroll,
+                        auto_scroll::auto_scroll,
+                        clamp_scroll_on_resize,
+                        track_scroll_momentum,
+                        momentum::fling_scroll,
+                        snap::snap_scroll,
+                        update_scroll_metrics,
+                        detect_reached_end,
+                        layout_cache::update_layout_cache,
+                        layout_cache::update_visible_children,
+                        scroll_update,
+                        scrollbar::update_scrollbar,
+                    )
+                        .chain()
+                        .in_set(ScrollSystem::Update),
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                transform_scroll_update
+                    .after(UiSystem::Layout)
+                    .before(TransformSystem::TransformPropagate),
             );
+        #[cfg(feature = "accessibility")]
+        app.add_systems(
+            Update,
+            accessibility::update_accessibility_node.after(update_scroll_metrics),
+        );
+        #[cfg(feature = "debug")]
+        app.register_type::<ScrollViewDebug>().add_systems(
+            Update,
+            debug::draw_scroll_debug
+                .run_if(resource_exists::<ScrollViewDebug>)
+                .after(ScrollSystem::Update),
+        );
     }
 }
 
 /// Root component of scroll, it should have clipped style.
+///
+/// The `ScrollableContent` has to be a direct child of the `ScrollView`:
+/// ```text
+/// ScrollView
+/// └── ScrollableContent
+///     └── items...
+/// ```
+/// Wrapping the content in another node breaks the scrolling, in that case a warning
+/// is logged. Other children of the view, e.g. absolutely positioned overlays or
+/// the scrollbars, can be placed before or after the content, the content is found
+/// by its `ScrollableContent` component. The view should have only one content.
+///
+/// The viewport used for the scroll bounds is the size of this node after the layout,
+/// so `min_height`/`max_height` and similar constraints are honored. Until the layout
+/// computes the size, the view ignores the scroll input.
+///
+/// Less common options are grouped in `input`, `animation` and `bounds`, so only the
+/// groups that matter need to be specified:
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_scroll_view::*;
+///
+/// let scroll_view = ScrollView {
+///     animation: ScrollAnimation {
+///         smooth_time: 0.1,
+///         ..default()
+///     },
+///     ..ScrollView::horizontal(60.0)
+/// };
+/// ```
 #[derive(Component, Debug, Reflect)]
 pub struct ScrollView {
-    /// Field which control speed of the scrolling.
-    /// Could be negative number to implement invert scroll
+    /// Whether the view reacts to the user input. Disabled views ignore the wheel, drag,
+    /// touch, keyboard and scrollbar input and pass it to the parent views, e.g. while
+    /// a modal is open. The events like `ScrollTo` still move the content.
+    /// Default is true.
+    pub enabled: bool,
+    /// Distance in pixels the content moves per line of the mouse wheel.
+    /// Wheels reporting pixels (e.g. touchpads) move the content by the reported distance
+    /// scaled by `ScrollInput::pixel_scroll_speed`.
+    /// Could be negative number to implement invert scroll, zero disables scrolling with
+    /// the mouse wheel. Dragging and touch use `ScrollInput::drag_speed` instead.
+    /// The horizontal axis can use a different speed set in
+    /// `ScrollInput::horizontal_scroll_speed`, and the wheel a fixed step set in
+    /// `ScrollInput::line_step`.
+    /// Default is 40.
     pub scroll_speed: f32,
-    /// Controls whether scroll events should propagate to parent scroll views
+    /// Controls whether scroll events should propagate to parent scroll views,
+    /// see `ScrollPropagation`.
+    /// Views which content fits, so there is nothing to scroll, pass the input to the parents
+    /// unless `ScrollInput::consume_when_fitting` is set.
+    /// Default is `ScrollPropagation::Never`.
+    pub propagate: ScrollPropagation,
+    /// Axes on which the content can be scrolled.
+    pub axis: ScrollAxis,
+    /// The horizontal start of the content is on its right, e.g. for right-to-left text
+    /// or content with `FlexDirection::RowReverse`. The content is aligned with the right
+    /// edge of the view and `pos_x` is measured from the right, so the start and end,
+    /// the progress, the wheel and the events like `ScrollTo` keep their meaning, while
+    /// the drag, touch and the horizontal scrollbar move in the physical directions.
+    /// It is read once when the `ScrollView` is added.
+    /// Default is false.
+    pub right_to_left: bool,
+    /// Moves the content using its `Transform` instead of `Style::top`/`Style::left`.
+    ///
+    /// Changing the `Style` every frame the content moves triggers the UI layout,
+    /// which can be expensive for huge content. The translation is applied after
+    /// the layout instead, so the layout is not recomputed while scrolling.
+    /// Note that anything reading the content position before the layout runs,
+    /// e.g. custom hit-testing done in `Update`, sees the position from the previous frame.
     /// Default is false.
-    pub propagate: bool,
-    /// Enable horizontal scrolling
-    pub horizontal: bool,
+    pub transform_scroll: bool,
+    /// Rounds the position of the content applied to its `Style` or `Transform` to whole
+    /// physical pixels, so text doesn't blur while scrolling. The offset in
+    /// `ScrollableContent` keeps its fractional part, so the motion stays smooth.
+    /// Default is false.
+    pub pixel_snap: bool,
+    /// Sets up the `Style` of the view when it is added: `align_items` to `Start`,
+    /// `align_self` to `Stretch` and `flex_direction` to `Row`, which lay out the content
+    /// from the start of the view. When disabled, these are left to the user, e.g. to
+    /// center short content.
+    ///
+    /// The view always gets `overflow` clipped, `justify_content` set to `FlexEnd` with
+    /// `right_to_left`, and the content `flex_shrink` set to zero, as the scrolling
+    /// doesn't work without them.
+    /// It is read once when the `ScrollView` is added.
+    /// Default is true.
+    pub manage_style: bool,
+    /// Options of the input handling.
+    pub input: ScrollInput,
+    /// Options of the scroll animation.
+    pub animation: ScrollAnimation,
+    /// Options of the scrollable range.
+    pub bounds: ScrollBounds,
 }
 
 impl Default for ScrollView {
     fn default() -> Self {
         Self {
-            scroll_speed: 200.0,
-            propagate: false,
-            horizontal: false,
+            enabled: true,
+            scroll_speed: 40.0,
+            propagate: ScrollPropagation::Never,
+            axis: ScrollAxis::Vertical,
+            right_to_left: false,
+            transform_scroll: false,
+            pixel_snap: false,
+            manage_style: true,
+            input: ScrollInput::default(),
+            animation: ScrollAnimation::default(),
+            bounds: ScrollBounds::default(),
+        }
+    }
+}
+
+impl From<f32> for ScrollView {
+    /// Creates the default `ScrollView` with given `scroll_speed`.
+    fn from(scroll_speed: f32) -> Self {
+        Self {
+            scroll_speed,
+            ..default()
+        }
+    }
+}
+
+impl ScrollView {
+    /// Creates a view scrolling vertically with given `scroll_speed`.
+    pub fn vertical(scroll_speed: f32) -> Self {
+        Self::from(scroll_speed).with_axis(ScrollAxis::Vertical)
+    }
+
+    /// Creates a view scrolling horizontally with given `scroll_speed`.
+    pub fn horizontal(scroll_speed: f32) -> Self {
+        Self::from(scroll_speed).with_axis(ScrollAxis::Horizontal)
+    }
+
+    /// Creates a view scrolling on both axes with given `scroll_speed`.
+    pub fn both(scroll_speed: f32) -> Self {
+        Self::from(scroll_speed).with_axis(ScrollAxis::Both)
+    }
+
+    /// Sets the `axis`.
+    pub fn with_axis(self, axis: ScrollAxis) -> Self {
+        Self { axis, ..self }
+    }
+
+    /// Sets the `scroll_speed`.
+    pub fn with_speed(self, scroll_speed: f32) -> Self {
+        Self {
+            scroll_speed,
+            ..self
+        }
+    }
+
+    /// Sets whether the input is passed to the parent views, see `propagate`.
+    /// `true` stands for `ScrollPropagation::AtBoundary`.
+    pub fn with_propagate(self, propagate: impl Into<ScrollPropagation>) -> Self {
+        Self {
+            propagate: propagate.into(),
+            ..self
+        }
+    }
+}
+
+impl ScrollView {
+    /// Converts a motion of the content on the screen to the change of the offset,
+    /// which is mirrored horizontally for `right_to_left` views.
+    pub(crate) fn physical_delta(&self, delta: Vec2) -> Vec2 {
+        if self.right_to_left {
+            Vec2::new(-delta.x, delta.y)
+        } else {
+            delta
+        }
+    }
+
+    /// Converts the start of a child of `size` placed `physical_offset` from the top left
+    /// corner of the content of `content_size` to the distance from the start of the content.
+    pub(crate) fn child_offset(
+        &self,
+        physical_offset: Vec2,
+        size: Vec2,
+        content_size: Vec2,
+    ) -> Vec2 {
+        if self.right_to_left {
+            Vec2::new(
+                content_size.x - physical_offset.x - size.x,
+                physical_offset.y,
+            )
+        } else {
+            physical_offset
+        }
+    }
+
+    /// Returns the `scroll_speed` of each axis.
+    pub(crate) fn axis_scroll_speed(&self) -> Vec2 {
+        let horizontal = self.input.horizontal_scroll_speed;
+        Vec2::new(horizontal.unwrap_or(self.scroll_speed), self.scroll_speed)
+    }
+
+    /// Returns the distance scrolled by a line of the mouse wheel on each axis.
+    fn wheel_line_step(&self) -> Vec2 {
+        self.input
+            .line_step
+            .map_or_else(|| self.axis_scroll_speed(), Vec2::splat)
+    }
+
+    /// Resolves a length of the `bounds` along the scroll axis for the view of given size.
+    /// `Val::Auto` resolves to zero.
+    pub(crate) fn resolve_along_axis(&self, value: Val, container_size: Vec2) -> Vec2 {
+        let resolve = |size: f32| value.resolve(size, container_size).unwrap_or(0.0);
+        match self.axis {
+            ScrollAxis::Vertical => Vec2::new(0.0, resolve(container_size.y)),
+            ScrollAxis::Horizontal => Vec2::new(resolve(container_size.x), 0.0),
+            ScrollAxis::Both => Vec2::new(resolve(container_size.x), resolve(container_size.y)),
+        }
+    }
+
+    /// Returns the leading and trailing `content_padding` for the view of given size.
+    pub(crate) fn padding(&self, container_size: Vec2) -> (Vec2, Vec2) {
+        let (leading, trailing) = self.bounds.content_padding;
+        (
+            self.resolve_along_axis(leading, container_size),
+            self.resolve_along_axis(trailing, container_size),
+        )
+    }
+
+    /// Returns the maximum scroll offset for both axes, including the `content_padding`.
+    pub(crate) fn max_scroll(&self, content_size: Vec2, container_size: Vec2) -> Vec2 {
+        let viewport = (container_size - self.bounds.viewport_inset).max(Vec2::ZERO);
+        (self.wrap_period(content_size, container_size) - viewport).max(Vec2::ZERO)
+    }
+
+    /// Size of the content with its padding, the offset wraps around it in `BoundaryMode::Wrap`.
+    fn wrap_period(&self, content_size: Vec2, container_size: Vec2) -> Vec2 {
+        let (leading, trailing) = self.padding(container_size);
+        content_size + leading + trailing
+    }
+
+    /// Brings the offset back into the range allowed by `ScrollBounds::boundary_mode`.
+    pub(crate) fn constrain(&self, pos: Vec2, content_size: Vec2, container_size: Vec2) -> Vec2 {
+        match self.bounds.boundary_mode {
+            BoundaryMode::Clamp => {
+                let max_scroll = self.max_scroll(content_size, container_size);
+                pos.clamp(-max_scroll, Vec2::ZERO)
+            }
+            BoundaryMode::Free => pos,
+            BoundaryMode::Wrap => {
+                let period = self.wrap_period(content_size, container_size);
+                let wrap = |pos: f32, period: f32| {
+                    if period > 0.0 {
+                        wrap_offset(pos, period)
+                    } else {
+                        0.0
+                    }
+                };
+                Vec2::new(wrap(pos.x, period.x), wrap(pos.y, period.y))
+            }
+        }
+    }
+}
+
+/// Axes on which the `ScrollView` content can be scrolled.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ScrollAxis {
+    /// Only vertical scrolling, default.
+    #[default]
+    Vertical,
+    /// Only horizontal scrolling.
+    Horizontal,
+    /// Scrolling on both axes at once, e.g. for panning a map.
+    Both,
+}
+
+impl ScrollAxis {
+    /// Returns true if the content can move vertically.
+    pub fn scrolls_vertically(self) -> bool {
+        self != ScrollAxis::Horizontal
+    }
+
+    /// Returns true if the content can move horizontally.
+    pub fn scrolls_horizontally(self) -> bool {
+        self != ScrollAxis::Vertical
+    }
+}
+
+/// When the wheel, drag or touch input of a `ScrollView` is passed to its parent views.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ScrollPropagation {
+    /// The view consumes the input, also when it reaches its start or end.
+    #[default]
+    Never,
+    /// The view scrolls until it reaches its start or end, then the part of the input
+    /// it could not apply moves the parent views, like nested scroll containers
+    /// in the browsers.
+    AtBoundary,
+    /// The whole input is passed to the parent views, which scroll together with the view.
+    Always,
+}
+
+impl From<bool> for ScrollPropagation {
+    /// Converts the former `propagate: bool`, `true` passes the input at the boundary.
+    fn from(propagate: bool) -> Self {
+        if propagate {
+            ScrollPropagation::AtBoundary
+        } else {
+            ScrollPropagation::Never
         }
     }
 }
 
 /// Component containing offset value of the scroll container to the parent.
 /// It is possible to update the field `pos_y` manually to move scrollview to desired location.
+///
+/// It has to be a direct child of the `ScrollView`, the scroll range is computed from the
+/// sizes of the two nodes. Padding or other wrappers belong inside of the content, e.g.
+/// as the `padding` of its `Style`, otherwise the content is not scrolled and a warning
+/// is logged.
+///
+/// The scroll range computed from the sizes is kept in the `ScrollMetrics` of the view,
+/// updated every frame also without any input.
 #[derive(Component, Debug, Reflect, Default)]
 pub struct ScrollableContent {
     /// Vertical scroll container offset
@@ -69,190 +531,1812 @@ pub struct ScrollableContent {
     pub pos_x: f32,
 }
 
-pub fn create_scroll_view(
-    mut commands: Commands,
-    mut q: Query<(Entity, &mut Style), Added<ScrollView>>,
-) {
-    for (e, mut style) in q.iter_mut() {
-        style.overflow = Overflow::clip();
-        style.align_items = AlignItems::Start;
-        style.align_self = AlignSelf::Stretch;
-        style.flex_direction = FlexDirection::Row;
-        commands.entity(e).insert(Interaction::None);
+impl ScrollableContent {
+    /// Returns how far the content is scrolled on each axis, from 0 at the start to 1
+    /// at the end, for the content placed in the `scroll_view` with given nodes.
+    /// Axes on which the content fits into the view return 0.
+    ///
+    /// The same value is available in `ScrollMetrics::progress` of the view,
+    /// which doesn't need the nodes.
+    pub fn progress(
+        &self,
+        scroll_view: &ScrollView,
+        content_node: &Node,
+        view_node: &Node,
+    ) -> Vec2 {
+        progress(
+            Vec2::new(self.pos_x, self.pos_y),
+            scroll_view.max_scroll(content_node.size(), view_node.size()),
+        )
     }
 }
 
-// Common helper function to handle scroll logic
-fn handle_scroll_for_view(
-    children: &Children,
-    scroll_view: &ScrollView,
-    node: &Node,
-    delta_x: f32,
-    delta_y: f32,
-    content_q: &mut Query<(&mut ScrollableContent, &Node)>,
-) -> (bool, bool) {
-    let container_size = node.size();
-    let mut scroll_applied = false;
-    let mut at_boundary = false;
+/// Component added to the `ScrollableContent` entity while it is animated towards
+/// the target offset, see `ScrollAnimation::smooth_time`.
+///
+/// It can be inserted manually to animate the content to any offset.
+/// Removing it stops the animation at the current position.
+#[derive(Component, Debug, Reflect, Default, Clone, Copy)]
+pub struct SmoothScroll {
+    /// Offset the content is moving to, always kept in the scrollable range.
+    pub target: Vec2,
+    /// Current velocity of the animation in pixels per second.
+    pub velocity: Vec2,
+    /// Approximate time in seconds to reach the target, zero jumps to it instantly.
+    pub smooth_time: f32,
+}
 
-    for &child in children.iter() {
-        if let Ok(item) = content_q.get_mut(child) {
-            let mut scroll = item.0;
-            let content_size = item.1.size();
-            
-            // Handle vertical scrolling
-            if !scroll_view.horizontal {
-                let max_scroll = (content_size.y - container_size.y).max(0.0);
-                let new_pos = scroll.pos_y + delta_y;
-                let will_hit_top = new_pos > 0.;
-                let will_hit_bottom = new_pos < -max_scroll;
-                
-                scroll.pos_y += delta_y;
-                scroll.pos_y = scroll.pos_y.clamp(-max_scroll, 0.);
-                
-                if max_scroll > 0.0 {
-                    if !will_hit_top && !will_hit_bottom {
-                        scroll_applied = true;
-                    } else {
-                        at_boundary = true;
-                    }
-                }
-            } else {
-                // Handle horizontal scrolling
-                let max_scroll = (content_size.x - container_size.x).max(0.0);
-                let new_pos = scroll.pos_x + delta_x;
-                let will_hit_left = new_pos > 0.;
-                let will_hit_right = new_pos < -max_scroll;
-                
-                scroll.pos_x += delta_x;
-                scroll.pos_x = scroll.pos_x.clamp(-max_scroll, 0.);
-                
-                if max_scroll > 0.0 {
-                    if !will_hit_left && !will_hit_right {
-                        scroll_applied = true;
-                    } else {
-                        at_boundary = true;
-                    }
-                }
-            }
+impl SmoothScroll {
+    /// Creates the animation to the `target` offset starting from rest.
+    pub fn to(target: Vec2, smooth_time: f32) -> Self {
+        Self {
+            target,
+            velocity: Vec2::ZERO,
+            smooth_time,
         }
     }
+}
 
-    let should_consume = !scroll_view.propagate && (scroll_applied || at_boundary);
-    (should_consume, at_boundary)
+/// Component added to the `ScrollView` entity with the current scroll velocity.
+///
+/// It is measured from the content movement every frame, so it reflects dragging,
+/// wheel and animated scrolling alike, and it is zero when the content is idle.
+#[derive(Component, Debug, Reflect, Default, Clone, Copy)]
+pub struct ScrollMomentum {
+    /// Scroll velocity in pixels per second.
+    pub velocity: Vec2,
+    last_pos: Option<Vec2>,
 }
 
-fn scroll_events(
-    mut scroll_evr: EventReader<MouseWheel>,
-    mut q: Query<(Entity, &Children, &Interaction, &ScrollView, &Node), With<ScrollView>>,
-    time: Res<Time>,
-    mut content_q: Query<(&mut ScrollableContent, &Node)>,
-) {
-    use bevy::input::mouse::MouseScrollUnit;
-    for ev in scroll_evr.read() {
-        let hovered_scrolls: Vec<_> = q
-            .iter_mut()
-            .filter(|(_, _, &interaction, _, _)| interaction == Interaction::Hovered)
-            .collect();
+/// Distance in pixels from the bottom of the content within which a view with
+/// `StickToBottom` stays pinned to the bottom.
+pub const STICK_TO_BOTTOM_TOLERANCE: f32 = 1.0;
 
-        let mut consumed = false;
-        
-        for (_entity, children, _, scroll_view, node) in hovered_scrolls.into_iter().rev() {
-            if consumed {
-                continue;
-            }
+/// Component for the `ScrollView` entity keeping the content scrolled to the bottom
+/// when it grows, e.g. for logs and chats, as long as the content was at the bottom
+/// before the change. When the user scrolled away from the bottom, the offset is left
+/// alone so the history can be read while new content arrives.
+///
+/// Content fitting into the view counts as being at the bottom, so the view also starts
+/// at the bottom once the content grows past it.
+#[derive(Component, Debug, Reflect, Default, Clone, Copy)]
+pub struct StickToBottom;
 
-            let scroll_amount = match ev.unit {
-                MouseScrollUnit::Line => {
-                    ev.y * time.delta().as_secs_f32() * scroll_view.scroll_speed
-                }
-                MouseScrollUnit::Pixel => ev.y,
-            } * time.delta().as_secs_f32() * scroll_view.scroll_speed;
+/// Component for the `ScrollView` entity keeping the visible content in place when the
+/// content grows or shrinks at its start, e.g. when older messages are loaded above
+/// the viewport of a feed. Every change of the content size is treated as happening
+/// before the viewport, so the offset moves by the change and the user doesn't see a jump.
+/// Like the `overflow-anchor` of the browsers, but without picking the anchor node.
+///
+/// Content growing at its end, e.g. appended messages, should use `StickToBottom` instead.
+#[derive(Component, Debug, Reflect, Default, Clone, Copy)]
+pub struct ScrollAnchoring;
 
-            // For horizontal scrolling, we'll use the vertical scroll as horizontal
-            let (delta_x, delta_y) = if scroll_view.horizontal {
-                (scroll_amount, 0.0)
-            } else {
-                (0.0, scroll_amount)
-            };
-            
-            let (should_consume, _) = handle_scroll_for_view(
-                children, 
-                scroll_view, 
-                node, 
-                delta_x,
-                delta_y, 
-                &mut content_q
-            );
-            
-            if should_consume {
-                consumed = true;
-            }
+/// Component added to the `ScrollView` entity with its current sizes and scroll range.
+/// It is updated every frame, also when there is no scroll input.
+/// The sizes are taken from the child with `ScrollableContent`, other children are ignored.
+#[derive(Component, Debug, Reflect, Default, Clone, Copy, PartialEq)]
+pub struct ScrollMetrics {
+    /// Size of the `ScrollView` node.
+    pub container_size: Vec2,
+    /// Size of the `ScrollableContent` node.
+    pub content_size: Vec2,
+    /// Maximum scroll offset on both axes, the content offset is in `-max_scroll..=0` range.
+    pub max_scroll: Vec2,
+    /// Current offset of the content, same as `pos_x` and `pos_y` of `ScrollableContent`.
+    pub offset: Vec2,
+}
+
+impl ScrollMetrics {
+    /// Returns how far the content is scrolled on each axis, from 0 at the start to 1
+    /// at the end. Axes on which the content fits into the view return 0.
+    pub fn progress(&self) -> Vec2 {
+        progress(self.offset, self.max_scroll)
+    }
+
+    /// Returns true when the content overflows the view on any axis, so there is
+    /// something to scroll, e.g. to decide whether to show a "more below" hint.
+    pub fn is_scrollable(&self) -> bool {
+        self.max_scroll.cmpgt(Vec2::ZERO).any()
+    }
+}
+
+/// Normalizes the offset to `0..=1` range of the scrollable range.
+fn progress(offset: Vec2, max_scroll: Vec2) -> Vec2 {
+    let progress = |offset: f32, max_scroll: f32| {
+        if max_scroll > 0.0 {
+            (-offset / max_scroll).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    };
+    Vec2::new(
+        progress(offset.x, max_scroll.x),
+        progress(offset.y, max_scroll.y),
+    )
+}
+
+/// Event sent when the offset of the content of the `ScrollView` changes, no matter
+/// whether it was moved by the input, an animation or by changing `ScrollableContent`.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct ScrollChanged {
+    /// `ScrollView` entity which content moved.
+    pub entity: Entity,
+    /// New offset of the content, same as `pos_x` and `pos_y` of `ScrollableContent`.
+    pub pos: Vec2,
+    /// New progress of the scrolling, see `ScrollMetrics::progress`.
+    pub progress: Vec2,
+}
+
+/// Event requesting the scroll view containing `child` to scroll so that `child` starts at the
+/// top (or left for horizontal views) of the viewport, or is aligned as set in `align`.
+///
+/// `child` does not have to be a direct child of the `ScrollableContent`, it can be nested
+/// under any number of intermediate nodes. When it is placed inside nested scroll views
+/// the innermost one is scrolled.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_scroll_view::*;
+///
+/// fn reveal_invalid_field(field: Entity, mut scroll_to: EventWriter<ScrollToChild>) {
+///     scroll_to.send(ScrollToChild::into_view(field));
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ScrollToChild {
+    /// Entity that should be scrolled into view.
+    pub child: Entity,
+    /// Alignment of the child within the viewport. `None` moves the content only as much
+    /// as needed to show the whole child, so a fully visible child stays in place.
+    pub align: Option<ScrollAnchor>,
+}
+
+impl ScrollToChild {
+    /// Scrolls the `child` to the start of the viewport.
+    pub fn new(child: Entity) -> Self {
+        Self {
+            child,
+            align: Some(ScrollAnchor::Start),
+        }
+    }
+
+    /// Scrolls by the least distance showing the whole `child`.
+    pub fn into_view(child: Entity) -> Self {
+        Self { child, align: None }
+    }
+
+    /// Aligns the child within the viewport with the given anchor.
+    pub fn aligned(self, align: ScrollAnchor) -> Self {
+        Self {
+            align: Some(align),
+            ..self
         }
     }
 }
 
-fn input_mouse_pressed_move(
-    mut motion_evr: EventReader<MouseMotion>,
-    mut q: Query<(&Children, &Interaction, &ScrollView, &Node), With<ScrollView>>,
-    mut content_q: Query<(&mut ScrollableContent, &Node)>,
-) {
-    for evt in motion_evr.read() {
-        let pressed_scrolls: Vec<_> = q
-            .iter_mut()
-            .filter(|(_, &interaction, _, _)| interaction == Interaction::Pressed)
-            .collect();
+/// Returns the offset of the content along one axis showing the child placed at `child_offset`
+/// from the start of the content, see `ScrollToChild::align`.
+fn child_offset_for(
+    align: Option<ScrollAnchor>,
+    pos: f32,
+    child_offset: f32,
+    child_size: f32,
+    viewport_size: f32,
+) -> f32 {
+    match align {
+        Some(align) => align.offset_for(child_offset, child_size, viewport_size),
+        // Children longer than the viewport show their start.
+        None if child_offset < -pos || child_size > viewport_size => -child_offset,
+        None if child_offset + child_size > viewport_size - pos => {
+            ScrollAnchor::End.offset_for(child_offset, child_size, viewport_size)
+        }
+        None => pos,
+    }
+}
 
-        let mut consumed = false;
+/// Event resetting the content of the given `ScrollView` to its start,
+/// without affecting any other scroll views.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ScrollToTop {
+    /// `ScrollView` entity, not the `ScrollableContent`.
+    pub view: Entity,
+}
 
-        for (children, _, scroll_view, node) in pressed_scrolls.into_iter().rev() {
-            if consumed {
-                continue;
-            }
+/// Event scrolling the content of the given `ScrollView` by a distance, the same way
+/// as the input does, so it respects `ScrollBounds::boundary_mode`.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_scroll_view::*;
+///
+/// fn page_down(view: Entity, mut scroll_by: EventWriter<ScrollBy>) {
+///     scroll_by.send(ScrollBy::pages(view, 1.0).animated(0.2));
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ScrollBy {
+    /// `ScrollView` entity, not the `ScrollableContent`.
+    pub view: Entity,
+    /// Distance to scroll.
+    pub distance: ScrollDistance,
+    /// Approximate time in seconds of the animation, zero scrolls instantly.
+    pub smooth_time: f32,
+}
 
-            let (should_consume, _) = handle_scroll_for_view(children, scroll_view, node, evt.delta.x, evt.delta.y, &mut content_q);
-            
-            if should_consume {
-                consumed = true;
+/// Event scrolling the content of the given `ScrollView` to an absolute position.
+/// The position is kept in the range allowed by `ScrollBounds::boundary_mode`,
+/// `f32::INFINITY` scrolls to the end of the content on its axis in every mode.
+/// It can be also given as a fraction of the scrollable range with `ScrollTo::progress`,
+/// e.g. to bind a slider to the view.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_scroll_view::*;
+///
+/// fn scroll_to_second_page(view: Entity, mut scroll_to: EventWriter<ScrollTo>) {
+///     scroll_to.send(ScrollTo::new(view, Vec2::new(0.0, 600.0)));
+/// }
+///
+/// fn scroll_to_middle(view: Entity, mut scroll_to: EventWriter<ScrollTo>) {
+///     scroll_to.send(ScrollTo::progress(view, Vec2::splat(0.5)));
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ScrollTo {
+    /// `ScrollView` entity, not the `ScrollableContent`.
+    pub view: Entity,
+    /// Position to scroll to. Axes the view does not scroll on are ignored.
+    pub pos: ScrollPosition,
+    /// Approximate time in seconds of the animation, zero scrolls instantly.
+    pub smooth_time: f32,
+}
+
+impl ScrollTo {
+    /// Scrolls to the given position.
+    pub fn new(view: Entity, pos: Vec2) -> Self {
+        Self {
+            view,
+            pos: ScrollPosition::Pixels(pos),
+            smooth_time: 0.0,
+        }
+    }
+
+    /// Scrolls to the start of the content, the top left corner.
+    pub fn start(view: Entity) -> Self {
+        Self::new(view, Vec2::ZERO)
+    }
+
+    /// Scrolls to the end of the content on the axes the view scrolls on, e.g. the bottom
+    /// of a vertical view. When the content fits into the view it stays at the start.
+    pub fn end(view: Entity) -> Self {
+        Self::new(view, Vec2::INFINITY)
+    }
+
+    /// Scrolls to the fraction of the scrollable range, clamped to `0..=1`,
+    /// the inverse of `ScrollMetrics::progress`.
+    pub fn progress(view: Entity, progress: Vec2) -> Self {
+        Self {
+            pos: ScrollPosition::Progress(progress),
+            ..Self::start(view)
+        }
+    }
+
+    /// Animates the scroll over approximately `smooth_time` seconds.
+    pub fn animated(self, smooth_time: f32) -> Self {
+        Self {
+            smooth_time,
+            ..self
+        }
+    }
+}
+
+/// Position of the `ScrollTo` event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollPosition {
+    /// Distance in pixels from the start of the content, so it is the negated offset
+    /// of the `ScrollableContent`.
+    Pixels(Vec2),
+    /// Fraction of the scrollable range on each axis, 0 at the start and 1 at the end.
+    Progress(Vec2),
+}
+
+impl ScrollPosition {
+    /// Resolves the position to pixels from the start of the content,
+    /// `None` when it is not a valid position.
+    fn to_pixels(self, max_scroll: Vec2) -> Option<Vec2> {
+        match self {
+            ScrollPosition::Pixels(pos) => {
+                if pos.is_nan() || pos.cmpeq(Vec2::NEG_INFINITY).any() {
+                    return None;
+                }
+                Some(Vec2::select(pos.cmpeq(Vec2::INFINITY), max_scroll, pos))
+            }
+            ScrollPosition::Progress(progress) => {
+                if progress.is_nan() {
+                    return None;
+                }
+                Some(progress.clamp(Vec2::ZERO, Vec2::ONE) * max_scroll)
             }
         }
     }
 }
 
-fn input_touch_pressed_move(
-    touches: Res<Touches>,
-    mut q: Query<(&Children, &Interaction, &ScrollView, &Node), With<ScrollView>>,
-    mut content_q: Query<(&mut ScrollableContent, &Node)>,
-) {
-    for t in touches.iter() {
-        let Some(touch) = touches.get_pressed(t.id()) else {
-            continue;
-        };
+/// Distance of the `ScrollBy` event. Positive values scroll towards the end of the content,
+/// i.e. down or right.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollDistance {
+    /// Distance in pixels on both axes.
+    Pixels(Vec2),
+    /// Number of lines of given height along the scroll axis.
+    Lines { count: f32, line_height: f32 },
+    /// Number of pages along the scroll axis, a page is the size of the view
+    /// multiplied by `ScrollInput::page_fraction`.
+    Pages(f32),
+}
 
-        let pressed_scrolls: Vec<_> = q
-            .iter_mut()
-            .filter(|(_, &interaction, _, _)| interaction == Interaction::Pressed)
-            .collect();
+impl ScrollBy {
+    /// Scrolls by the given number of pixels.
+    pub fn pixels(view: Entity, pixels: Vec2) -> Self {
+        Self::new(view, ScrollDistance::Pixels(pixels))
+    }
 
-        let mut consumed = false;
+    /// Scrolls by `count` lines of `line_height` pixels.
+    pub fn lines(view: Entity, count: f32, line_height: f32) -> Self {
+        Self::new(view, ScrollDistance::Lines { count, line_height })
+    }
 
-        for (children, _, scroll_view, node) in pressed_scrolls.into_iter().rev() {
-            if consumed {
-                continue;
-            }
+    /// Scrolls by `count` pages.
+    pub fn pages(view: Entity, count: f32) -> Self {
+        Self::new(view, ScrollDistance::Pages(count))
+    }
 
-            let (should_consume, _) = handle_scroll_for_view(children, scroll_view, node, touch.delta().x, touch.delta().y, &mut content_q);
-            
-            if should_consume {
-                consumed = true;
+    /// Animates the scroll over approximately `smooth_time` seconds.
+    pub fn animated(self, smooth_time: f32) -> Self {
+        Self {
+            smooth_time,
+            ..self
+        }
+    }
+
+    fn new(view: Entity, distance: ScrollDistance) -> Self {
+        Self {
+            view,
+            distance,
+            smooth_time: 0.0,
+        }
+    }
+}
+
+impl ScrollDistance {
+    /// Returns the distance in pixels for the view of given size.
+    pub(crate) fn to_pixels(self, scroll_view: &ScrollView, container_size: Vec2) -> Vec2 {
+        let along_axis = |distance: f32| match scroll_view.axis {
+            ScrollAxis::Horizontal => Vec2::new(distance, 0.0),
+            _ => Vec2::new(0.0, distance),
+        };
+        match self {
+            ScrollDistance::Pixels(pixels) => pixels,
+            ScrollDistance::Lines { count, line_height } => along_axis(count * line_height),
+            ScrollDistance::Pages(count) => {
+                let page = if scroll_view.axis == ScrollAxis::Horizontal {
+                    container_size.x
+                } else {
+                    container_size.y
+                };
+                along_axis(count * page * scroll_view.input.page_fraction)
             }
         }
     }
 }
 
-fn scroll_update(mut q: Query<(&ScrollableContent, &mut Style), Changed<ScrollableContent>>) {
-    for (scroll, mut style) in q.iter_mut() {
-        style.top = Val::Px(scroll.pos_y);
-        style.left = Val::Px(scroll.pos_x);
+/// Event sent by the input systems for every scroll view that was offered a scroll input,
+/// starting from the innermost one.
+///
+/// When the last event for given input has `consumed` set to false, the input fell through
+/// all the scroll views and can be handled by custom code.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct ScrollConsumed {
+    /// `ScrollView` entity that handled the input.
+    pub entity: Entity,
+    /// Whether the input was swallowed by this scroll view.
+    pub consumed: bool,
+}
+
+/// Event sent once when the content of the `ScrollView` is scrolled within
+/// `ScrollBounds::end_threshold` from its end, useful for infinite scrolling.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollReachedEnd {
+    /// `ScrollView` entity that reached the end.
+    pub entity: Entity,
+}
+
+/// Event sent once when the content of the `ScrollView` arrives at one of its edges,
+/// e.g. to refresh at the top or to load the next page at the bottom. Holding the content
+/// at the edge doesn't repeat it, it is sent again after the content leaves the edge
+/// and comes back. Axes on which the content fits into the view have no edges.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollBoundary {
+    /// `ScrollView` entity which content reached the edge.
+    pub entity: Entity,
+    /// Edge of the content shown in the view.
+    pub edge: ScrollEdge,
+}
+
+/// Edge of the content, see `ScrollBoundary`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ScrollEdge {
+    /// Top edge, the start of the vertical scrolling.
+    Top,
+    /// Bottom edge, the end of the vertical scrolling.
+    Bottom,
+    /// Left edge, the start of the horizontal scrolling unless `right_to_left` is set.
+    Left,
+    /// Right edge, the end of the horizontal scrolling unless `right_to_left` is set.
+    Right,
+}
+
+impl ScrollEdge {
+    pub(crate) const ALL: [ScrollEdge; 4] = [
+        ScrollEdge::Top,
+        ScrollEdge::Bottom,
+        ScrollEdge::Left,
+        ScrollEdge::Right,
+    ];
+
+    /// Returns true when the content at `offset` shows this edge.
+    pub(crate) fn is_reached(self, offset: Vec2, max_scroll: Vec2, right_to_left: bool) -> bool {
+        let at_start = offset.cmpge(Vec2::ZERO);
+        let at_end = offset.cmple(-max_scroll);
+        let (left, right) = if right_to_left {
+            (at_end.test(0), at_start.test(0))
+        } else {
+            (at_start.test(0), at_end.test(0))
+        };
+        match self {
+            ScrollEdge::Top => max_scroll.y > 0.0 && at_start.test(1),
+            ScrollEdge::Bottom => max_scroll.y > 0.0 && at_end.test(1),
+            ScrollEdge::Left => max_scroll.x > 0.0 && left,
+            ScrollEdge::Right => max_scroll.x > 0.0 && right,
+        }
+    }
+}
+
+/// Debounce state of the `ScrollReachedEnd` event, added to the `ScrollView` entity.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ReachedEndState {
+    armed: bool,
+    fired_at: f64,
+    content_size: f32,
+}
+
+/// Sets up newly added `ScrollView` and `ScrollableContent` nodes, it has to run first.
+pub fn create_scroll_view(
+    mut commands: Commands,
+    mut q: Query<(Entity, &ScrollView, &mut Style), (Added<ScrollView>, With<Node>)>,
+    mut content_q: Query<&mut Style, (Added<ScrollableContent>, Without<ScrollView>)>,
+    missing_node: Query<
+        (Entity, Has<ScrollView>),
+        (
+            Or<(Added<ScrollView>, Added<ScrollableContent>)>,
+            Without<Node>,
+        ),
+    >,
+) {
+    // Everything is computed from the layout, so entities which are not UI nodes are ignored.
+    for (e, is_view) in missing_node.iter() {
+        let component = if is_view {
+            "ScrollView"
+        } else {
+            "ScrollableContent"
+        };
+        warn!(
+            "{component} added to {e:?} which is not a UI node, it will be ignored. \
+             Add it to an entity with `NodeBundle`."
+        );
+    }
+    // The view lays out its children in a row, so wide content would be shrunk
+    // to the viewport width and never overflow horizontally.
+    for mut style in content_q.iter_mut() {
+        style.flex_shrink = 0.0;
+    }
+    for (e, scroll_view, mut style) in q.iter_mut() {
+        style.overflow = Overflow::clip();
+        if scroll_view.manage_style {
+            style.align_items = AlignItems::Start;
+            style.align_self = AlignSelf::Stretch;
+            style.flex_direction = FlexDirection::Row;
+        }
+        if scroll_view.right_to_left {
+            style.justify_content = JustifyContent::FlexEnd;
+        }
+        let mut entity = commands.entity(e);
+        entity.insert((
+            ScrollInteraction::default(),
+            ScrollMomentum::default(),
+            momentum::Fling::default(),
+            ScrollMetrics::default(),
+        ));
+        if scroll_view.input.use_interaction {
+            entity.insert(Interaction::None);
+        }
+    }
+}
+
+/// Warns about `ScrollableContent` wrapped in extra nodes, which is never scrolled,
+/// about views with children but no `ScrollableContent`, and about views with more
+/// than one, of which only the first is scrolled by the input.
+pub fn warn_nested_content(
+    views: Query<(Entity, &Children), (With<ScrollView>, Changed<Children>)>,
+    children_q: Query<&Children>,
+    content_q: Query<(), With<ScrollableContent>>,
+    view_q: Query<(), With<ScrollView>>,
+) {
+    for (entity, children) in views.iter() {
+        let mut contents = children.iter().filter(|&&child| content_q.contains(child));
+        if let Some(first) = contents.next() {
+            let extra: Vec<Entity> = contents.copied().collect();
+            if !extra.is_empty() {
+                warn!(
+                    "ScrollView {entity:?} has more than one ScrollableContent child, only \
+                     {first:?} is scrolled by the input, {extra:?} are ignored. \
+                     Remove `ScrollableContent` from the other children."
+                );
+            }
+            continue;
+        }
+        // Nested views own the content below them, so the search stops at them.
+        let mut stack: Vec<Entity> = children.iter().copied().collect();
+        let mut nested = None;
+        while let Some(descendant) = stack.pop() {
+            if content_q.contains(descendant) {
+                nested = Some(descendant);
+                break;
+            }
+            if !view_q.contains(descendant) {
+                if let Ok(grandchildren) = children_q.get(descendant) {
+                    stack.extend(grandchildren.iter());
+                }
+            }
+        }
+        if let Some(nested) = nested {
+            warn!(
+                "ScrollableContent {nested:?} is not a direct child of ScrollView {entity:?}, \
+                 it will not be scrolled. Remove the nodes wrapping it."
+            );
+        } else {
+            warn!(
+                "ScrollView {entity:?} has no ScrollableContent child, it will not scroll. \
+                 Add `ScrollableContent` to the node containing the scrolled children."
+            );
+        }
+    }
+}
+
+/// Result of `apply_scroll_delta`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollOutcome {
+    /// Whether the offset moved.
+    pub applied: bool,
+    /// Whether the offset hit the start or the end of the content.
+    pub at_boundary: bool,
+    /// Part of the delta the view could not apply, the built-in input passes it on
+    /// to the parent views when the input is not consumed.
+    pub remaining: Vec2,
+    /// Whether the built-in input would stop at this view, see `ScrollView::propagate`.
+    pub consumed: bool,
+}
+
+/// Moves the `content` by `delta` pixels the same way the built-in input does, on the axes
+/// of the `scroll_view` and within its `ScrollBounds`. Useful for custom input sources,
+/// the sizes are the `Node` sizes of the content and of the view.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_scroll_view::*;
+///
+/// fn scroll_up(
+///     views: Query<(&ScrollView, &Node, &Children)>,
+///     mut content_q: Query<(&mut ScrollableContent, &Node)>,
+/// ) {
+///     for (scroll_view, node, children) in views.iter() {
+///         for &child in children.iter() {
+///             if let Ok((mut content, content_node)) = content_q.get_mut(child) {
+///                 let size = content_node.size();
+///                 apply_scroll_delta(scroll_view, &mut content, size, node.size(), Vec2::Y);
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn apply_scroll_delta(
+    scroll_view: &ScrollView,
+    content: &mut ScrollableContent,
+    content_size: Vec2,
+    container_size: Vec2,
+    delta: Vec2,
+) -> ScrollOutcome {
+    let mut pos = Vec2::new(content.pos_x, content.pos_y);
+    let (applied, at_boundary, moved) =
+        scroll_offset(scroll_view, &mut pos, content_size, container_size, delta);
+    if pos.x != content.pos_x {
+        content.pos_x = pos.x;
+    }
+    if pos.y != content.pos_y {
+        content.pos_y = pos.y;
+    }
+    let remaining = delta - moved;
+    ScrollOutcome {
+        applied,
+        at_boundary,
+        remaining,
+        consumed: should_consume(scroll_view, applied, at_boundary, remaining),
+    }
+}
+
+/// Returns the content child of the view matching `content_q`. Only the first
+/// `ScrollableContent` child is scrolled, see `warn_nested_content`.
+pub(crate) fn content_child<D: QueryData, F: QueryFilter>(
+    children: &Children,
+    content_q: &Query<D, F>,
+) -> Option<Entity> {
+    children
+        .iter()
+        .copied()
+        .find(|&child| content_q.contains(child))
+}
+
+// Common helper function to handle scroll logic.
+// Returns whether the input should be consumed and the part of `delta` the view could not
+// apply, which is passed on to the parent views when the input is not consumed.
+// Only the first `ScrollableContent` child is scrolled, see `warn_nested_content`.
+pub(crate) fn handle_scroll_for_view(
+    children: &Children,
+    scroll_view: &ScrollView,
+    node: &Node,
+    delta: Vec2,
+    content_q: &mut Query<(&mut ScrollableContent, &Node)>,
+) -> (bool, Vec2) {
+    if !is_laid_out(node) {
+        return (false, delta);
+    }
+    let Some(child) = content_child(children, content_q) else {
+        return (should_consume(scroll_view, false, false, delta), delta);
+    };
+    let Ok((mut scroll, content_node)) = content_q.get_mut(child) else {
+        return (false, delta);
+    };
+    let outcome = apply_scroll_delta(
+        scroll_view,
+        &mut scroll,
+        content_node.size(),
+        node.size(),
+        delta,
+    );
+    (
+        outcome.consumed,
+        passed_to_parent(scroll_view, delta, outcome.remaining),
+    )
+}
+
+/// Same as `handle_scroll_for_view`, but moves the `SmoothScroll` target instead of
+/// the content itself. The content follows the target in `smooth_scroll` system.
+pub(crate) fn handle_smooth_scroll_for_view(
+    commands: &mut Commands,
+    children: &Children,
+    scroll_view: &ScrollView,
+    node: &Node,
+    delta: Vec2,
+    content_q: &Query<(&mut ScrollableContent, &Node)>,
+    smooth_q: &mut Query<&mut SmoothScroll>,
+) -> (bool, Vec2) {
+    if !is_laid_out(node) {
+        return (false, delta);
+    }
+    let Some((child, (scroll, content_node))) = children
+        .iter()
+        .find_map(|&child| Some((child, content_q.get(child).ok()?)))
+    else {
+        return (should_consume(scroll_view, false, false, delta), delta);
+    };
+    let (content_size, container_size) = (content_node.size(), node.size());
+    let (applied, at_boundary, moved) = match smooth_q.get_mut(child) {
+        Ok(mut smooth) => scroll_offset(
+            scroll_view,
+            &mut smooth.target,
+            content_size,
+            container_size,
+            delta,
+        ),
+        Err(_) => {
+            let mut target = Vec2::new(scroll.pos_x, scroll.pos_y);
+            let result = scroll_offset(
+                scroll_view,
+                &mut target,
+                content_size,
+                container_size,
+                delta,
+            );
+            commands
+                .entity(child)
+                .insert(SmoothScroll::to(target, scroll_view.animation.smooth_time));
+            result
+        }
+    };
+    let remaining = delta - moved;
+    (
+        should_consume(scroll_view, applied, at_boundary, remaining),
+        passed_to_parent(scroll_view, delta, remaining),
+    )
+}
+
+/// Decides whether the input stops at this view or is offered to the parent views:
+///
+/// | view state                      | `Never`          | `AtBoundary`        | `Always`         |
+/// |---------------------------------|------------------|---------------------|------------------|
+/// | applied the whole delta         | consumed         | consumed            | passed to parent |
+/// | reached the boundary            | consumed         | remainder to parent | passed to parent |
+/// | content fits, nothing to scroll | passed to parent | passed to parent    | passed to parent |
+///
+/// With `ScrollInput::consume_when_fitting` views with `ScrollPropagation::Never` consume
+/// the input also when the content fits.
+fn should_consume(
+    scroll_view: &ScrollView,
+    applied: bool,
+    at_boundary: bool,
+    remaining: Vec2,
+) -> bool {
+    match scroll_view.propagate {
+        ScrollPropagation::Never => {
+            applied || at_boundary || scroll_view.input.consume_when_fitting
+        }
+        ScrollPropagation::AtBoundary => applied && remaining == Vec2::ZERO,
+        ScrollPropagation::Always => false,
+    }
+}
+
+/// Returns the part of `delta` offered to the parent views, the `remaining` part the view
+/// could not apply, or all of it with `ScrollPropagation::Always`.
+fn passed_to_parent(scroll_view: &ScrollView, delta: Vec2, remaining: Vec2) -> Vec2 {
+    match scroll_view.propagate {
+        ScrollPropagation::Always => delta,
+        _ => remaining,
+    }
+}
+
+/// Returns false for views which size was not computed by the layout yet, or which are hidden
+/// with `Display::None`. Their size is zero, so any scroll range computed from it is wrong.
+pub(crate) fn is_laid_out(node: &Node) -> bool {
+    node.size() != Vec2::ZERO
+}
+
+/// Moves the offset by `delta` on the axes enabled in the `scroll_view`.
+/// Returns whether the scroll was applied, whether it hit the boundary
+/// and the part of `delta` that moved the offset.
+fn scroll_offset(
+    scroll_view: &ScrollView,
+    pos: &mut Vec2,
+    content_size: Vec2,
+    container_size: Vec2,
+    delta: Vec2,
+) -> (bool, bool, Vec2) {
+    // NaN survives clamping, so it would leave the view stuck in an invalid state.
+    if !delta.is_finite() {
+        bevy::log::warn_once!("Ignoring non-finite scroll delta {delta}, check `scroll_speed`");
+        return (false, false, Vec2::ZERO);
+    }
+    let mode = scroll_view.bounds.boundary_mode;
+    let max_scroll = scroll_view.max_scroll(content_size, container_size);
+    let period = scroll_view.wrap_period(content_size, container_size);
+    let mut scroll_applied = false;
+    let mut at_boundary = false;
+    let mut moved = Vec2::ZERO;
+
+    // Handle vertical scrolling
+    if scroll_view.axis.scrolls_vertically() {
+        let (applied, boundary, axis_moved) =
+            scroll_axis(mode, &mut pos.y, delta.y, max_scroll.y, period.y);
+        scroll_applied |= applied;
+        at_boundary |= boundary;
+        moved.y = axis_moved;
+    }
+    // Handle horizontal scrolling
+    if scroll_view.axis.scrolls_horizontally() {
+        let (applied, boundary, axis_moved) =
+            scroll_axis(mode, &mut pos.x, delta.x, max_scroll.x, period.x);
+        scroll_applied |= applied;
+        at_boundary |= boundary;
+        moved.x = axis_moved;
+    }
+    (scroll_applied, at_boundary, moved)
+}
+
+/// Moves a single axis offset by `delta` and keeps it in the range given by `mode`.
+/// Returns whether the scroll was applied, whether it hit the boundary
+/// and how much of `delta` was applied.
+fn scroll_axis(
+    mode: BoundaryMode,
+    pos: &mut f32,
+    delta: f32,
+    max_scroll: f32,
+    period: f32,
+) -> (bool, bool, f32) {
+    let new_pos = *pos + delta;
+    match mode {
+        BoundaryMode::Free => {
+            *pos = new_pos;
+            return (delta != 0.0, false, delta);
+        }
+        BoundaryMode::Wrap if period > 0.0 => {
+            *pos = wrap_offset(new_pos, period);
+            return (delta != 0.0, false, delta);
+        }
+        _ => {}
+    }
+    let will_hit_start = new_pos > 0.;
+    let will_hit_end = new_pos < -max_scroll;
+
+    let old_pos = *pos;
+    *pos = new_pos.clamp(-max_scroll, 0.);
+    let moved = *pos - old_pos;
+
+    if max_scroll <= 0.0 {
+        (false, false, 0.0)
+    } else if !will_hit_start && !will_hit_end {
+        (true, false, moved)
+    } else {
+        (false, true, moved)
+    }
+}
+
+/// Wraps the offset into the `(-period, 0]` range.
+fn wrap_offset(pos: f32, period: f32) -> f32 {
+    -(-pos).rem_euclid(period)
+}
+
+/// Converts the wheel input to pixels, `scroll_speed` pixels per line on each axis.
+/// Pixel input is scaled by `pixel_speed`, only its direction follows the sign
+/// of `scroll_speed`.
+fn wheel_distance(lines: Vec2, pixels: Vec2, scroll_speed: Vec2, pixel_speed: f32) -> Vec2 {
+    Vec2::select(
+        scroll_speed.cmpeq(Vec2::ZERO),
+        Vec2::ZERO,
+        lines * scroll_speed + pixels * scroll_speed.signum() * pixel_speed,
+    )
+}
+
+/// Returns the number of physical pixels per pixel of the UI layout. The wheel reporting
+/// pixels and the mouse motion are in physical pixels, while the node sizes and the offsets
+/// are in the pixels of the UI, scaled by the window scale factor and `UiScale`.
+pub(crate) fn physical_pixel_scale(window: Option<&Window>, ui_scale: &UiScale) -> f32 {
+    window.map_or(1.0, Window::scale_factor) * ui_scale.0
+}
+
+/// Returns the sign applied to the input delta.
+fn direction(invert: bool) -> f32 {
+    if invert {
+        -1.0
+    } else {
+        1.0
+    }
+}
+
+/// Returns the maximum scroll offset of the content placed in the view.
+fn view_max_scroll(
+    children: &Children,
+    scroll_view: &ScrollView,
+    node: &Node,
+    content_q: &Query<(&mut ScrollableContent, &Node)>,
+) -> Option<Vec2> {
+    children
+        .iter()
+        .find_map(|&child| content_q.get(child).ok())
+        .map(|(_, content_node)| scroll_view.max_scroll(content_node.size(), node.size()))
+}
+
+/// Returns how many times the content is longer than the view on each axis, at least one,
+/// used by `ScrollInput::adaptive_speed`.
+fn content_length_ratio(
+    children: &Children,
+    node: &Node,
+    content_q: &Query<(&mut ScrollableContent, &Node)>,
+) -> Vec2 {
+    if !is_laid_out(node) {
+        return Vec2::ONE;
+    }
+    children
+        .iter()
+        .find_map(|&child| content_q.get(child).ok())
+        .map_or(Vec2::ONE, |(_, content_node)| {
+            (content_node.size() / node.size()).max(Vec2::ONE)
+        })
+}
+
+/// Scrolls the hovered views with the mouse wheel, or the `ActiveScrollView` with
+/// `ScrollInput::wheel_when_active`.
+///
+/// Pressed views are not hovered, so while a view is dragged with the mouse or touch,
+/// the drag takes precedence and the wheel input of the same frames is ignored by it.
+/// Each view is moved by only one of the input sources in a frame.
+#[allow(clippy::too_many_arguments)]
+pub fn scroll_events(
+    mut commands: Commands,
+    mut scroll_evr: EventReader<MouseWheel>,
+    keys: Res<ButtonInput<KeyCode>>,
+    active: Res<ActiveScrollView>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    q: Query<(Entity, &Children, &ScrollInteraction, &ScrollView, &Node), With<ScrollView>>,
+    parents: Query<&Parent>,
+    mut content_q: Query<(&mut ScrollableContent, &Node)>,
+    mut smooth_q: Query<&mut SmoothScroll>,
+    mut consumed_evw: EventWriter<ScrollConsumed>,
+) {
+    use bevy::input::mouse::MouseScrollUnit;
+    // Sum all the wheel events received this frame, so hit-testing and consuming
+    // is done once no matter how the OS batches the events.
+    let (mut lines, mut pixels) = (Vec2::ZERO, Vec2::ZERO);
+    for ev in scroll_evr.read() {
+        match ev.unit {
+            MouseScrollUnit::Line => lines += Vec2::new(ev.x, ev.y),
+            MouseScrollUnit::Pixel => pixels += Vec2::new(ev.x, ev.y),
+        }
+    }
+    if lines == Vec2::ZERO && pixels == Vec2::ZERO {
+        return;
+    }
+    let pixels = pixels / physical_pixel_scale(windows.get_single().ok(), &ui_scale);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    // The active view with `wheel_when_active` and its parents take the wheel wherever
+    // the pointer is, otherwise it goes to the hovered views.
+    let routed_to_active = active.0.filter(|&view| {
+        q.get(view)
+            .is_ok_and(|(.., scroll_view, _)| scroll_view.input.wheel_when_active)
+    });
+    let target_scrolls: Vec<_> = match routed_to_active {
+        Some(view) => std::iter::once(view)
+            .chain(parents.iter_ancestors(view))
+            .filter_map(|entity| q.get(entity).ok())
+            .filter(|(.., scroll_view, _)| scroll_view.enabled)
+            .collect(),
+        None => q
+            .iter()
+            .filter(|(_, _, interaction, scroll_view, _)| {
+                interaction.0 == Interaction::Hovered && scroll_view.enabled
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .collect(),
+    };
+
+    // Part of the wheel input not applied by the inner views yet, views scale the input
+    // by their own speed, so it is tracked as a fraction.
+    let mut share = Vec2::ONE;
+    for (entity, children, _, scroll_view, node) in target_scrolls {
+        let speed = if scroll_view.input.adaptive_speed {
+            scroll_view.wheel_line_step() * content_length_ratio(children, node, &content_q)
+        } else {
+            scroll_view.wheel_line_step()
+        };
+        let pixel_speed = scroll_view.input.pixel_scroll_speed;
+        let sign = direction(scroll_view.input.invert_wheel);
+        let scroll_amount = wheel_distance(lines, pixels, speed, pixel_speed) * sign * share;
+
+        // Horizontal wheel input is used as is, the vertical wheel scrolls the horizontal
+        // axis only when there is no horizontal input.
+        let y_to_x = scroll_amount.x == 0.0
+            && match scroll_view.axis {
+                ScrollAxis::Vertical => false,
+                ScrollAxis::Horizontal => {
+                    scroll_view.input.wheel_y_scrolls_horizontal
+                        || (shift && scroll_view.input.shift_wheel_horizontal)
+                }
+                ScrollAxis::Both => {
+                    (shift && scroll_view.input.shift_wheel_horizontal)
+                        || (scroll_view.input.wheel_axis_fallback
+                            && view_max_scroll(children, scroll_view, node, &content_q)
+                                .is_some_and(|max| max.y <= 0.0 && max.x > 0.0))
+                }
+            };
+        let delta = if y_to_x {
+            // The vertical wheel moves the horizontal axis at its speed.
+            let distance = wheel_distance(lines.yx(), pixels.yx(), speed, pixel_speed).x;
+            Vec2::new(distance * sign * share.y, 0.0)
+        } else {
+            scroll_amount
+        };
+
+        let delta = match scroll_view.input.max_wheel_step {
+            Some(max) => delta.clamp(Vec2::splat(-max.abs()), Vec2::splat(max.abs())),
+            None => delta,
+        };
+        // The start and end keep their meaning in `right_to_left` views, so unlike the drag
+        // the wheel is not mirrored and still scrolls towards the end.
+        let (should_consume, remaining) = if scroll_view.animation.smooth_time > 0.0 {
+            handle_smooth_scroll_for_view(
+                &mut commands,
+                children,
+                scroll_view,
+                node,
+                delta,
+                &content_q,
+                &mut smooth_q,
+            )
+        } else {
+            handle_scroll_for_view(children, scroll_view, node, delta, &mut content_q)
+        };
+
+        consumed_evw.send(ScrollConsumed {
+            entity,
+            consumed: should_consume,
+        });
+        if should_consume {
+            break;
+        }
+        if y_to_x {
+            share.y *= unapplied_fraction(remaining.x, delta.x);
+        } else {
+            share.x *= unapplied_fraction(remaining.x, delta.x);
+            share.y *= unapplied_fraction(remaining.y, delta.y);
+        }
+    }
+}
+
+/// Fraction of the `delta` left after the view applied its part.
+fn unapplied_fraction(remaining: f32, delta: f32) -> f32 {
+    if delta != 0.0 {
+        remaining / delta
+    } else {
+        1.0
+    }
+}
+
+/// Applies the drag or touch `motion` to the view and returns whether it was consumed,
+/// together with the motion left for the parent views.
+///
+/// The motion along the axes the content does not overflow on is not applied, so a
+/// diagonal drag does not move the content sideways, e.g. in `BoundaryMode::Free`.
+fn drag_view(
+    children: &Children,
+    scroll_view: &ScrollView,
+    node: &Node,
+    motion: Vec2,
+    invert: bool,
+    content_q: &mut Query<(&mut ScrollableContent, &Node)>,
+) -> (bool, Vec2) {
+    let factor = direction(invert) * scroll_view.input.drag_speed;
+    let overflows = view_max_scroll(children, scroll_view, node, content_q)
+        .map_or(Vec2::ZERO, |max| {
+            Vec2::select(max.cmpgt(Vec2::ZERO), Vec2::ONE, Vec2::ZERO)
+        });
+    let delta = scroll_view.physical_delta(motion) * factor;
+    let (should_consume, remaining) = match scroll_view.animation.overscroll {
+        Overscroll::Elastic { max_px, .. }
+            if scroll_view.bounds.boundary_mode == BoundaryMode::Clamp
+                && overflows != Vec2::ZERO =>
+        {
+            // The view absorbs the whole drag, also past the boundary.
+            let child = content_child(children, content_q);
+            if let Some(Ok((mut scroll, content_node))) = child.map(|c| content_q.get_mut(c)) {
+                let max_scroll = scroll_view.max_scroll(content_node.size(), node.size());
+                let mut pos = Vec2::new(scroll.pos_x, scroll.pos_y);
+                momentum::elastic_drag(&mut pos, delta * overflows, max_scroll, max_px);
+                scroll.pos_x = pos.x;
+                scroll.pos_y = pos.y;
+            }
+            (true, Vec2::ZERO)
+        }
+        _ => handle_scroll_for_view(children, scroll_view, node, delta * overflows, content_q),
+    };
+    let remaining = remaining + delta * (Vec2::ONE - overflows);
+    if factor != 0.0 {
+        (
+            should_consume,
+            scroll_view.physical_delta(remaining / factor),
+        )
+    } else {
+        (should_consume, motion)
+    }
+}
+
+/// Scrolls the pressed views by dragging with the mouse, the wheel is ignored by them
+/// meanwhile, see `scroll_events`.
+pub fn input_mouse_pressed_move(
+    mut motion_evr: EventReader<MouseMotion>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    mut q: Query<(Entity, &Children, &ScrollInteraction, &ScrollView, &Node), With<ScrollView>>,
+    mut content_q: Query<(&mut ScrollableContent, &Node)>,
+    mut consumed_evw: EventWriter<ScrollConsumed>,
+) {
+    let scale = physical_pixel_scale(windows.get_single().ok(), &ui_scale);
+    for evt in motion_evr.read() {
+        let pressed_scrolls: Vec<_> = q
+            .iter_mut()
+            .filter(|(_, _, interaction, scroll_view, _)| {
+                interaction.0 == Interaction::Pressed && scroll_view.enabled
+            })
+            .collect();
+
+        let mut consumed = false;
+        // Motion not applied by the inner views yet.
+        let mut motion = evt.delta / scale;
+
+        for (entity, children, _, scroll_view, node) in pressed_scrolls.into_iter().rev() {
+            if consumed {
+                continue;
+            }
+
+            let (should_consume, remaining) = drag_view(
+                children,
+                scroll_view,
+                node,
+                motion,
+                scroll_view.input.invert_drag,
+                &mut content_q,
+            );
+            motion = remaining;
+
+            consumed_evw.send(ScrollConsumed {
+                entity,
+                consumed: should_consume,
+            });
+            if should_consume {
+                consumed = true;
+            }
+        }
+    }
+}
+
+/// Scrolls the views under the start of the touch.
+///
+/// The views are found from where the touch started instead of their `Interaction`, because
+/// a touch starting on a button in the content leaves the view only hovered. Until the touch
+/// travels past `ScrollInput::touch_drag_threshold` it is a tap meant for the button.
+///
+/// Only the first finger touching the screen scrolls, so more fingers don't multiply
+/// the speed. When it is lifted the scrolling stops until a new touch starts, instead
+/// of jumping to the position of another finger still touching the screen.
+pub fn input_touch_pressed_move(
+    touches: Res<Touches>,
+    ui_scale: Res<UiScale>,
+    mut primary: Local<Option<u64>>,
+    mut touched_views: Local<Vec<Entity>>,
+    q: Query<(
+        Entity,
+        &Children,
+        &ScrollView,
+        &Node,
+        &GlobalTransform,
+        Option<&CalculatedClip>,
+        Option<&InheritedVisibility>,
+    )>,
+    mut content_q: Query<(&mut ScrollableContent, &Node)>,
+    mut consumed_evw: EventWriter<ScrollConsumed>,
+) {
+    if primary.is_some_and(|id| touches.get_pressed(id).is_none()) {
+        *primary = None;
+        touched_views.clear();
+    }
+    if primary.is_none() {
+        if let Some(touch) = touches.iter_just_pressed().next() {
+            *primary = Some(touch.id());
+            // Touches are reported in logical pixels.
+            let start = touch.start_position() / ui_scale.0;
+            *touched_views = q
+                .iter()
+                .filter(|(_, _, _, node, transform, clip, visibility)| {
+                    let rect = node.logical_rect(transform);
+                    let rect = clip.map_or(rect, |clip| rect.intersect(clip.clip));
+                    pointer::is_visible(*visibility, node) && rect.contains(start)
+                })
+                .map(|(entity, ..)| entity)
+                .collect();
+        }
+    }
+    let Some(touch) = primary.and_then(|id| touches.get_pressed(id)) else {
+        return;
+    };
+
+    // Until the touch travels past the threshold it is treated as a tap meant
+    // for the children, e.g. buttons placed in the content.
+    let distance = touch.distance().length();
+    // Motion not applied by the inner views yet.
+    let mut motion = touch.delta() / ui_scale.0;
+    for &entity in touched_views.iter().rev() {
+        let Ok((entity, children, scroll_view, node, ..)) = q.get(entity) else {
+            continue;
+        };
+        if !scroll_view.enabled || distance < scroll_view.input.touch_drag_threshold {
+            continue;
+        }
+
+        let (should_consume, remaining) = drag_view(
+            children,
+            scroll_view,
+            node,
+            motion,
+            scroll_view.input.invert_touch,
+            &mut content_q,
+        );
+        motion = remaining;
+
+        consumed_evw.send(ScrollConsumed {
+            entity,
+            consumed: should_consume,
+        });
+        if should_consume {
+            break;
+        }
+    }
+}
+
+/// Handles the `ScrollBy` events.
+pub fn scroll_by(
+    mut commands: Commands,
+    mut events: EventReader<ScrollBy>,
+    views: Query<(&ScrollView, &Node, &Children)>,
+    mut content_q: Query<(&mut ScrollableContent, &Node, Option<&mut SmoothScroll>)>,
+) {
+    for ev in events.read() {
+        let Ok((scroll_view, node, children)) = views.get(ev.view) else {
+            continue;
+        };
+        if !is_laid_out(node) {
+            continue;
+        }
+        // Scrolling towards the end moves the content in the negative direction.
+        let delta = -ev.distance.to_pixels(scroll_view, node.size());
+        let Some(child) = content_child(children, &content_q) else {
+            continue;
+        };
+        let Ok((mut scroll, content_node, smooth)) = content_q.get_mut(child) else {
+            continue;
+        };
+        let (content_size, container_size) = (content_node.size(), node.size());
+        if ev.smooth_time > 0.0 {
+            match smooth {
+                Some(mut smooth) => {
+                    scroll_offset(
+                        scroll_view,
+                        &mut smooth.target,
+                        content_size,
+                        container_size,
+                        delta,
+                    );
+                    smooth.smooth_time = ev.smooth_time;
+                }
+                None => {
+                    let mut target = Vec2::new(scroll.pos_x, scroll.pos_y);
+                    scroll_offset(
+                        scroll_view,
+                        &mut target,
+                        content_size,
+                        container_size,
+                        delta,
+                    );
+                    commands
+                        .entity(child)
+                        .insert(SmoothScroll::to(target, ev.smooth_time));
+                }
+            }
+        } else {
+            apply_scroll_delta(
+                scroll_view,
+                &mut scroll,
+                content_size,
+                container_size,
+                delta,
+            );
+            commands.entity(child).remove::<SmoothScroll>();
+        }
+    }
+}
+
+/// Handles the `ScrollTo` events.
+pub fn scroll_to(
+    mut commands: Commands,
+    mut events: EventReader<ScrollTo>,
+    views: Query<(&ScrollView, &Node, &Children)>,
+    mut content_q: Query<(&mut ScrollableContent, &Node)>,
+) {
+    for ev in events.read() {
+        let Ok((scroll_view, node, children)) = views.get(ev.view) else {
+            continue;
+        };
+        if !is_laid_out(node) {
+            continue;
+        }
+        let Some(child) = content_child(children, &content_q) else {
+            continue;
+        };
+        let Ok((mut scroll, content_node)) = content_q.get_mut(child) else {
+            continue;
+        };
+        let max_scroll = scroll_view.max_scroll(content_node.size(), node.size());
+        let Some(pos) = ev.pos.to_pixels(max_scroll) else {
+            continue;
+        };
+        let mut target = Vec2::new(scroll.pos_x, scroll.pos_y);
+        if scroll_view.axis.scrolls_horizontally() {
+            target.x = -pos.x;
+        }
+        if scroll_view.axis.scrolls_vertically() {
+            target.y = -pos.y;
+        }
+        let target = scroll_view.constrain(target, content_node.size(), node.size());
+        if ev.smooth_time > 0.0 {
+            commands
+                .entity(child)
+                .insert(SmoothScroll::to(target, ev.smooth_time));
+        } else {
+            scroll.pos_x = target.x;
+            scroll.pos_y = target.y;
+            commands.entity(child).remove::<SmoothScroll>();
+        }
+    }
+}
+
+/// Handles the `ScrollToTop` events.
+pub fn scroll_to_top(
+    mut commands: Commands,
+    mut events: EventReader<ScrollToTop>,
+    views: Query<&Children, With<ScrollView>>,
+    mut content_q: Query<&mut ScrollableContent>,
+) {
+    for ev in events.read() {
+        let Ok(children) = views.get(ev.view) else {
+            continue;
+        };
+        let Some(child) = content_child(children, &content_q) else {
+            continue;
+        };
+        if let Ok(mut scroll) = content_q.get_mut(child) {
+            scroll.pos_x = 0.0;
+            scroll.pos_y = 0.0;
+            commands.entity(child).remove::<SmoothScroll>();
+        }
+    }
+}
+
+/// Handles the `ScrollToChild` events.
+pub fn scroll_to_child(
+    mut commands: Commands,
+    mut events: EventReader<ScrollToChild>,
+    parents: Query<&Parent>,
+    views: Query<(&ScrollView, &Node)>,
+    nodes: Query<(&Node, &GlobalTransform)>,
+    mut content_q: Query<&mut ScrollableContent>,
+) {
+    for ev in events.read() {
+        // Walk up the hierarchy until we find the innermost content node placed in a scroll view.
+        let mut current = ev.child;
+        let mut found = None;
+        while let Ok(parent) = parents.get(current) {
+            let parent = parent.get();
+            if content_q.contains(parent) {
+                if let Some(view) = parents.get(parent).ok().map(Parent::get) {
+                    if views.contains(view) {
+                        found = Some((view, parent));
+                        break;
+                    }
+                }
+            }
+            current = parent;
+        }
+        let Some((view, content)) = found else {
+            continue;
+        };
+        let (
+            Ok((scroll_view, view_node)),
+            Ok((content_node, content_transform)),
+            Ok((child_node, child_transform)),
+        ) = (views.get(view), nodes.get(content), nodes.get(ev.child))
+        else {
+            continue;
+        };
+
+        // UI transforms point at the center of the node, so convert the child center into the
+        // content local space and move it to the top left corner of both nodes.
+        let child_center = content_transform
+            .affine()
+            .inverse()
+            .transform_point3(child_transform.translation())
+            .truncate();
+        let physical = child_center + content_node.size() / 2.0 - child_node.size() / 2.0;
+        let offset = scroll_view.child_offset(physical, child_node.size(), content_node.size())
+            + scroll_view.padding(view_node.size()).0;
+        let max_scroll = scroll_view.max_scroll(content_node.size(), view_node.size());
+
+        let Ok(mut scroll) = content_q.get_mut(content) else {
+            continue;
+        };
+        commands.entity(content).remove::<SmoothScroll>();
+        let size = child_node.size();
+        let viewport = view_node.size();
+        if scroll_view.axis.scrolls_horizontally() {
+            let x = child_offset_for(ev.align, scroll.pos_x, offset.x, size.x, viewport.x);
+            scroll.pos_x = x.clamp(-max_scroll.x, 0.);
+        }
+        if scroll_view.axis.scrolls_vertically() {
+            let y = child_offset_for(ev.align, scroll.pos_y, offset.y, size.y, viewport.y);
+            scroll.pos_y = y.clamp(-max_scroll.y, 0.);
+        }
+    }
+}
+
+/// Moves the content towards its `SmoothScroll` target with a critically damped spring,
+/// so it never overshoots. The target is re-clamped every frame, because the container
+/// or the content can be resized during the animation.
+pub fn smooth_scroll(
+    mut commands: Commands,
+    time: Res<Time>,
+    views: Query<(&ScrollView, &Node, &ScrollInteraction)>,
+    mut q: Query<(
+        Entity,
+        &Parent,
+        &Node,
+        &mut ScrollableContent,
+        &mut SmoothScroll,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    for (entity, parent, node, mut scroll, mut smooth) in q.iter_mut() {
+        let Ok((scroll_view, view_node, interaction)) = views.get(parent.get()) else {
+            continue;
+        };
+        // Grabbing the content interrupts the animation.
+        if interaction.0 == Interaction::Pressed {
+            commands.entity(entity).remove::<SmoothScroll>();
+            continue;
+        }
+        if scroll_view.bounds.boundary_mode == BoundaryMode::Clamp {
+            let max_scroll = scroll_view.max_scroll(node.size(), view_node.size());
+            smooth.target = smooth.target.clamp(-max_scroll, Vec2::ZERO);
+        }
+
+        let current = Vec2::new(scroll.pos_x, scroll.pos_y);
+        let (mut pos, velocity) = if smooth.smooth_time > 0.0 {
+            smooth_damp(
+                current,
+                smooth.target,
+                smooth.velocity,
+                smooth.smooth_time,
+                dt,
+            )
+        } else {
+            (smooth.target, Vec2::ZERO)
+        };
+        smooth.velocity = velocity;
+        if scroll_view.bounds.boundary_mode == BoundaryMode::Wrap {
+            // Shift the target together with the wrapped offset, so the animation continues
+            // in the same direction.
+            let wrapped = scroll_view.constrain(pos, node.size(), view_node.size());
+            smooth.target += wrapped - pos;
+            pos = wrapped;
+        }
+        if pos.distance(smooth.target) < 0.5 {
+            pos = smooth.target;
+            commands.entity(entity).remove::<SmoothScroll>();
+        }
+        scroll.pos_x = pos.x;
+        scroll.pos_y = pos.y;
+    }
+}
+
+/// Critically damped spring from Game Programming Gems 4, chapter 1.10.
+/// Returns the new position and velocity.
+fn smooth_damp(
+    current: Vec2,
+    target: Vec2,
+    velocity: Vec2,
+    smooth_time: f32,
+    dt: f32,
+) -> (Vec2, Vec2) {
+    let omega = 2.0 / smooth_time;
+    let x = omega * dt;
+    let exp = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+    let change = current - target;
+    let temp = (velocity + omega * change) * dt;
+    let mut velocity = (velocity - omega * temp) * exp;
+    let mut pos = target + (change + temp) * exp;
+
+    // Prevent overshooting the target on any axis.
+    for axis in 0..2 {
+        if (target[axis] - current[axis]) * (pos[axis] - target[axis]) > 0.0 {
+            pos[axis] = target[axis];
+            velocity[axis] = 0.0;
+        }
+    }
+    (pos, velocity)
+}
+
+/// Keeps the offset in the valid range when the size of the container or content changes,
+/// e.g. when rows get hidden with `Display::None`. The content size is read after the layout
+/// so only visible, laid-out children are taken into account.
+///
+/// Views with `ScrollAnchoring` are moved by the change of the content size, and views with
+/// `StickToBottom` which were at the bottom of the previous size are moved to the new bottom.
+pub fn clamp_scroll_on_resize(
+    views: Query<(
+        Entity,
+        &ScrollView,
+        &Node,
+        &Children,
+        Option<&ScrollMetrics>,
+        Has<StickToBottom>,
+        Has<ScrollAnchoring>,
+    )>,
+    changed: Query<(), Changed<Node>>,
+    mut content_q: Query<(&mut ScrollableContent, &Node)>,
+) {
+    for (entity, scroll_view, node, children, metrics, stick_to_bottom, anchoring) in views.iter() {
+        if !is_laid_out(node) {
+            continue;
+        }
+        for &child in children.iter() {
+            if !changed.contains(entity) && !changed.contains(child) {
+                continue;
+            }
+            let Ok((mut scroll, content_node)) = content_q.get_mut(child) else {
+                continue;
+            };
+            // The metrics still hold the range before the change.
+            let at_bottom = stick_to_bottom
+                && metrics.is_some_and(|metrics| {
+                    scroll.pos_y <= -metrics.max_scroll.y + STICK_TO_BOTTOM_TOLERANCE
+                });
+            let mut pos = Vec2::new(scroll.pos_x, scroll.pos_y);
+            // The content size of the metrics is zero until the first layout, there is
+            // nothing to keep in place yet.
+            if let Some(metrics) =
+                metrics.filter(|metrics| anchoring && metrics.content_size != Vec2::ZERO)
+            {
+                let growth = content_node.size() - metrics.content_size;
+                if scroll_view.axis.scrolls_horizontally() {
+                    pos.x -= growth.x;
+                }
+                if scroll_view.axis.scrolls_vertically() {
+                    pos.y -= growth.y;
+                }
+            }
+            let mut pos = scroll_view.constrain(pos, content_node.size(), node.size());
+            if at_bottom {
+                pos.y = -scroll_view.max_scroll(content_node.size(), node.size()).y;
+            }
+            if pos.x != scroll.pos_x {
+                scroll.pos_x = pos.x;
+            }
+            if pos.y != scroll.pos_y {
+                scroll.pos_y = pos.y;
+            }
+        }
+    }
+}
+
+/// Updates the `ScrollMetrics` of the views and sends the `ScrollChanged`
+/// and `ScrollBoundary` events.
+pub fn update_scroll_metrics(
+    mut views: Query<(Entity, &ScrollView, &Node, &Children, &mut ScrollMetrics)>,
+    content_q: Query<(&ScrollableContent, &Node)>,
+    mut changed_evw: EventWriter<ScrollChanged>,
+    mut boundary_evw: EventWriter<ScrollBoundary>,
+) {
+    for (entity, scroll_view, node, children, mut metrics) in views.iter_mut() {
+        let Some((scroll, content_node)) = children.iter().find_map(|&c| content_q.get(c).ok())
+        else {
+            continue;
+        };
+        let new_metrics = ScrollMetrics {
+            container_size: node.size(),
+            content_size: content_node.size(),
+            max_scroll: scroll_view.max_scroll(content_node.size(), node.size()),
+            offset: Vec2::new(scroll.pos_x, scroll.pos_y),
+        };
+        if metrics.offset != new_metrics.offset {
+            changed_evw.send(ScrollChanged {
+                entity,
+                pos: new_metrics.offset,
+                progress: new_metrics.progress(),
+            });
+            let rtl = scroll_view.right_to_left;
+            for edge in ScrollEdge::ALL {
+                if edge.is_reached(new_metrics.offset, new_metrics.max_scroll, rtl)
+                    && !edge.is_reached(metrics.offset, metrics.max_scroll, rtl)
+                {
+                    boundary_evw.send(ScrollBoundary { entity, edge });
+                }
+            }
+        }
+        // Avoid triggering change detection every frame while idle.
+        if *metrics != new_metrics {
+            *metrics = new_metrics;
+        }
+    }
+}
+
+/// Sends the `ScrollReachedEnd` events.
+pub fn detect_reached_end(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut views: Query<(
+        Entity,
+        &ScrollView,
+        &Node,
+        &Children,
+        Option<&mut ReachedEndState>,
+    )>,
+    content_q: Query<(&ScrollableContent, &Node)>,
+    mut reached_end_evw: EventWriter<ScrollReachedEnd>,
+) {
+    let now = time.elapsed_seconds_f64();
+    for (entity, scroll_view, node, children, state) in views.iter_mut() {
+        let Some(threshold) = scroll_view.bounds.end_threshold else {
+            continue;
+        };
+        let Some((scroll, content_node)) = children.iter().find_map(|&c| content_q.get(c).ok())
+        else {
+            continue;
+        };
+        if !is_laid_out(node) || !is_laid_out(content_node) {
+            continue;
+        }
+        let threshold = scroll_view.resolve_along_axis(threshold, node.size());
+        let max_scroll = scroll_view.max_scroll(content_node.size(), node.size());
+        let (pos, max_scroll, content_size, threshold) =
+            if scroll_view.axis == ScrollAxis::Horizontal {
+                (
+                    scroll.pos_x,
+                    max_scroll.x,
+                    content_node.size().x,
+                    threshold.x,
+                )
+            } else {
+                (
+                    scroll.pos_y,
+                    max_scroll.y,
+                    content_node.size().y,
+                    threshold.y,
+                )
+            };
+        // Content which fits into the view has no end to reach, so there is nothing to load.
+        if max_scroll <= 0.0 {
+            continue;
+        }
+        let near_end = pos + max_scroll <= threshold;
+
+        let Some(mut state) = state else {
+            commands.entity(entity).insert(ReachedEndState {
+                armed: true,
+                fired_at: 0.0,
+                content_size,
+            });
+            continue;
+        };
+        if !near_end {
+            state.armed = true;
+            continue;
+        }
+        let cooled_down = now - state.fired_at >= scroll_view.bounds.load_more_cooldown as f64
+            && content_size <= state.content_size;
+        if state.armed || cooled_down {
+            reached_end_evw.send(ScrollReachedEnd { entity });
+            *state = ReachedEndState {
+                armed: false,
+                fired_at: now,
+                content_size,
+            };
+        }
+    }
+}
+
+/// Updates the `ScrollMomentum` of the views.
+pub fn track_scroll_momentum(
+    time: Res<Time>,
+    mut views: Query<(&Children, &mut ScrollMomentum)>,
+    content_q: Query<&ScrollableContent>,
+) {
+    let dt = time.delta_seconds();
+    for (children, mut momentum) in views.iter_mut() {
+        let Some(scroll) = children.iter().find_map(|&c| content_q.get(c).ok()) else {
+            continue;
+        };
+        let pos = Vec2::new(scroll.pos_x, scroll.pos_y);
+        let velocity = match momentum.last_pos {
+            Some(last_pos) if dt > 0.0 => (pos - last_pos) / dt,
+            _ => Vec2::ZERO,
+        };
+        // Avoid triggering change detection every frame while idle.
+        if momentum.velocity != velocity {
+            momentum.velocity = velocity;
+        }
+        if momentum.last_pos != Some(pos) {
+            momentum.last_pos = Some(pos);
+        }
+    }
+}
+
+/// Applies the offset of the `ScrollableContent` to its `Style`.
+pub fn scroll_update(
+    mut q: Query<(Ref<ScrollableContent>, &Parent, &mut Style)>,
+    views: Query<(&ScrollView, Ref<Node>)>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+) {
+    let scale = physical_pixel_scale(windows.get_single().ok(), &ui_scale);
+    for (scroll, parent, mut style) in q.iter_mut() {
+        let Ok((view, view_node)) = views.get(parent.get()) else {
+            if scroll.is_changed() {
+                style.top = Val::Px(scroll.pos_y);
+                style.left = Val::Px(scroll.pos_x);
+            }
+            continue;
+        };
+        // The padding can be relative to the view size, so resizing the view moves the content.
+        if !scroll.is_changed() && !view_node.is_changed() {
+            continue;
+        }
+        if view.transform_scroll {
+            // Offset is applied in `transform_scroll_update`, only reset the style once.
+            if style.top != Val::Px(0.0) || style.left != Val::Px(0.0) {
+                style.top = Val::Px(0.0);
+                style.left = Val::Px(0.0);
+            }
+            continue;
+        }
+        // Shift the content by the leading padding, so the offset range stays the same.
+        let offset = Vec2::new(scroll.pos_x, scroll.pos_y) + view.padding(view_node.size()).0;
+        let offset = content_position(view, offset, scale);
+        let top = Val::Px(offset.y);
+        let x = Val::Px(offset.x);
+        // Right-to-left content is aligned with the right edge of the view.
+        let (left, right) = if view.right_to_left {
+            (Val::Auto, x)
+        } else {
+            (x, Val::Auto)
+        };
+        if style.top != top || style.left != left || style.right != right {
+            style.top = top;
+            style.left = left;
+            style.right = right;
+        }
+    }
+}
+
+/// Applies the scroll offset to the `Transform` of the content for views using
+/// `ScrollView::transform_scroll`. Runs right after the layout resets the translation.
+pub fn transform_scroll_update(
+    mut q: Query<(&ScrollableContent, &Parent, &mut Transform)>,
+    views: Query<(&ScrollView, &Node)>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+) {
+    let scale = physical_pixel_scale(windows.get_single().ok(), &ui_scale);
+    for (scroll, parent, mut transform) in q.iter_mut() {
+        let Ok((view, view_node)) = views.get(parent.get()) else {
+            continue;
+        };
+        if !view.transform_scroll {
+            continue;
+        }
+        let offset = Vec2::new(scroll.pos_x, scroll.pos_y) + view.padding(view_node.size()).0;
+        let offset = content_position(view, offset, scale);
+        transform.translation += view.physical_delta(offset).extend(0.0);
+    }
+}
+
+/// Returns the position of the content for its `offset`, rounded to whole physical pixels
+/// with `ScrollView::pixel_snap` given `scale` physical pixels per pixel of the UI.
+fn content_position(view: &ScrollView, offset: Vec2, scale: f32) -> Vec2 {
+    if view.pixel_snap && scale > 0.0 {
+        (offset * scale).round() / scale
+    } else {
+        offset
     }
 }
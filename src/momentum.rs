@@ -0,0 +1,215 @@
+use bevy::prelude::*;
+
+use crate::{
+    pointer::ScrollInteraction, BoundaryMode, Overscroll, ScrollMomentum, ScrollView,
+    ScrollableContent, SmoothScroll,
+};
+
+/// Speed in pixels per second below which the fling stops.
+const MIN_FLING_SPEED: f32 = 10.0;
+/// Stiffness of the spring pulling the content back after bouncing past the boundary.
+const BOUNCE_STIFFNESS: f32 = 150.0;
+
+/// Fling state of the `ScrollView`, see `ScrollAnimation::fling`.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct Fling {
+    velocity: Vec2,
+    release_velocity: Vec2,
+    pressed: bool,
+}
+
+/// Keeps the content moving after the drag is released, see `ScrollAnimation::fling`.
+pub fn fling_scroll(
+    time: Res<Time>,
+    mut views: Query<(
+        &ScrollView,
+        &ScrollInteraction,
+        &ScrollMomentum,
+        &Node,
+        &Children,
+        &mut Fling,
+    )>,
+    mut content_q: Query<(&mut ScrollableContent, &Node), Without<SmoothScroll>>,
+) {
+    let dt = time.delta_seconds();
+    for (scroll_view, interaction, momentum, node, children, mut fling) in views.iter_mut() {
+        if interaction.0 == Interaction::Pressed {
+            // Mouse motion does not arrive every frame, so the velocity is averaged.
+            let release_velocity = fling.release_velocity.lerp(momentum.velocity, 0.5);
+            *fling = Fling {
+                velocity: Vec2::ZERO,
+                release_velocity,
+                pressed: true,
+            };
+            continue;
+        }
+        if fling.pressed {
+            let animation = &scroll_view.animation;
+            let velocity = if animation.fling
+                && fling.release_velocity.length() >= animation.min_fling_velocity
+            {
+                fling.release_velocity
+            } else {
+                Vec2::ZERO
+            };
+            *fling = Fling {
+                velocity,
+                ..default()
+            };
+        }
+
+        for &child in children.iter() {
+            let Ok((mut scroll, content_node)) = content_q.get_mut(child) else {
+                continue;
+            };
+            let max_scroll = scroll_view.max_scroll(content_node.size(), node.size());
+            let bounce = bounce_stiffness(scroll_view);
+            let mut pos = Vec2::new(scroll.pos_x, scroll.pos_y);
+            if fling.velocity == Vec2::ZERO
+                && (bounce.is_none() || overscroll(pos, max_scroll) == Vec2::ZERO)
+            {
+                continue;
+            }
+
+            let friction = scroll_view.animation.fling_friction;
+            let clamp = scroll_view.bounds.boundary_mode == BoundaryMode::Clamp;
+            let mut velocity = fling.velocity;
+            if scroll_view.axis.scrolls_vertically() {
+                fling_axis(
+                    &mut pos.y,
+                    &mut velocity.y,
+                    max_scroll.y,
+                    friction,
+                    clamp,
+                    bounce,
+                    dt,
+                );
+            }
+            if scroll_view.axis.scrolls_horizontally() {
+                fling_axis(
+                    &mut pos.x,
+                    &mut velocity.x,
+                    max_scroll.x,
+                    friction,
+                    clamp,
+                    bounce,
+                    dt,
+                );
+            }
+            if !clamp {
+                pos = scroll_view.constrain(pos, content_node.size(), node.size());
+            }
+            fling.velocity = velocity;
+            if scroll.pos_x != pos.x {
+                scroll.pos_x = pos.x;
+            }
+            if scroll.pos_y != pos.y {
+                scroll.pos_y = pos.y;
+            }
+        }
+    }
+}
+
+/// Returns the stiffness of the spring pulling the content back from past the boundary,
+/// or `None` when the content can't move past it.
+fn bounce_stiffness(scroll_view: &ScrollView) -> Option<f32> {
+    if scroll_view.bounds.boundary_mode != BoundaryMode::Clamp {
+        return None;
+    }
+    match scroll_view.animation.overscroll {
+        Overscroll::Elastic { stiffness, .. } => Some(stiffness),
+        Overscroll::None if scroll_view.animation.bounce => Some(BOUNCE_STIFFNESS),
+        Overscroll::None => None,
+    }
+}
+
+/// Moves the offset by the drag `delta` with `Overscroll::Elastic`. Past the boundary
+/// the content follows the drag less and less, approaching `max_overscroll`.
+pub(crate) fn elastic_drag(pos: &mut Vec2, delta: Vec2, max_scroll: Vec2, max_overscroll: f32) {
+    for axis in 0..2 {
+        if delta[axis] == 0.0 || max_scroll[axis] <= 0.0 {
+            continue;
+        }
+        // Undo the rubber band, so the drag distance past the boundary adds up linearly.
+        let boundary = pos[axis].clamp(-max_scroll[axis], 0.0);
+        let dragged =
+            boundary + rubber_band_inverse(pos[axis] - boundary, max_overscroll) + delta[axis];
+        let boundary = dragged.clamp(-max_scroll[axis], 0.0);
+        pos[axis] = boundary + rubber_band(dragged - boundary, max_overscroll);
+    }
+}
+
+/// Maps the distance dragged past the boundary to the overscroll, which approaches `limit`.
+fn rubber_band(distance: f32, limit: f32) -> f32 {
+    if limit <= 0.0 {
+        return 0.0;
+    }
+    let distance_abs = distance.abs();
+    (limit * distance_abs / (distance_abs + limit)).copysign(distance)
+}
+
+/// Inverse of `rubber_band`.
+fn rubber_band_inverse(over: f32, limit: f32) -> f32 {
+    if limit <= 0.0 {
+        return 0.0;
+    }
+    let over_abs = over.abs().min(limit * 0.99);
+    (limit * over_abs / (limit - over_abs)).copysign(over)
+}
+
+/// Distance by which the offset is outside of the scrollable range on each axis.
+fn overscroll(pos: Vec2, max_scroll: Vec2) -> Vec2 {
+    pos - pos.clamp(-max_scroll, Vec2::ZERO)
+}
+
+/// Moves a single axis by the fling velocity. With `clamp` and without `bounce` the fling
+/// stops hard at the boundary, with `bounce` stiffness the content moves past it and a spring
+/// pulls it back. Without `clamp` the fling only slows down, the `BoundaryMode` is applied
+/// to the offset afterwards.
+fn fling_axis(
+    pos: &mut f32,
+    velocity: &mut f32,
+    max_scroll: f32,
+    friction: f32,
+    clamp: bool,
+    bounce: Option<f32>,
+    dt: f32,
+) {
+    if !clamp {
+        *velocity *= (-friction * dt).exp();
+        *pos += *velocity * dt;
+        if velocity.abs() < MIN_FLING_SPEED {
+            *velocity = 0.0;
+        }
+        return;
+    }
+    let over = *pos - pos.clamp(-max_scroll, 0.0);
+    match bounce {
+        Some(stiffness) if over != 0.0 => {
+            // Critically damped, so the content returns without oscillating.
+            let damping = 2.0 * stiffness.sqrt();
+            *velocity += (-stiffness * over - damping * *velocity) * dt;
+        }
+        _ => *velocity *= (-friction * dt).exp(),
+    }
+    *pos += *velocity * dt;
+
+    let boundary = pos.clamp(-max_scroll, 0.0);
+    let new_over = *pos - boundary;
+    if bounce.is_none() {
+        if new_over != 0.0 {
+            *pos = boundary;
+            *velocity = 0.0;
+        }
+    } else if over != 0.0 && (new_over == 0.0 || new_over.signum() != over.signum()) {
+        // Back in the range after bouncing.
+        *pos = if over > 0.0 { 0.0 } else { -max_scroll };
+        *velocity = 0.0;
+    } else if new_over != 0.0 && new_over.abs() < 0.5 && velocity.abs() < MIN_FLING_SPEED {
+        *pos = boundary;
+        *velocity = 0.0;
+    }
+    if new_over == 0.0 && velocity.abs() < MIN_FLING_SPEED {
+        *velocity = 0.0;
+    }
+}
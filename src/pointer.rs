@@ -0,0 +1,91 @@
+use bevy::{hierarchy::HierarchyQueryExt, prelude::*, utils::HashSet, window::PrimaryWindow};
+
+use crate::ScrollView;
+
+/// Hover and press state of the `ScrollView` read by the input systems.
+///
+/// It is copied from the `Interaction` of the view, or resolved from the pointer position
+/// when `ScrollInput::use_interaction` is disabled. The Bevy UI doesn't update the
+/// `Interaction` of nodes below the nodes blocking the focus, e.g. buttons, so the views
+/// containing a hovered node are treated as hovered too.
+///
+/// Views hidden with `Visibility::Hidden` or `Display::None`, also on their ancestors,
+/// are never hovered or pressed, so they ignore the input, e.g. on inactive tabs.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollInteraction(pub Interaction);
+
+/// Updates the `ScrollInteraction` of the views, it has to run before the input systems.
+pub fn update_scroll_interaction(
+    mut views: Query<(
+        Entity,
+        &ScrollView,
+        Option<&Interaction>,
+        &Node,
+        &GlobalTransform,
+        Option<&CalculatedClip>,
+        Option<&InheritedVisibility>,
+        &mut ScrollInteraction,
+    )>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    ui_scale: Res<UiScale>,
+    interactions: Query<(Entity, &Interaction), Without<ScrollView>>,
+    parents: Query<&Parent>,
+) {
+    let over_children: HashSet<Entity> = interactions
+        .iter()
+        .filter(|(_, interaction)| **interaction != Interaction::None)
+        .flat_map(|(entity, _)| parents.iter_ancestors(entity))
+        .filter(|&ancestor| views.contains(ancestor))
+        .collect();
+
+    let cursor = windows
+        .get_single()
+        .ok()
+        .and_then(Window::cursor_position)
+        .map(|cursor| cursor / ui_scale.0);
+    let held = mouse.pressed(MouseButton::Left) || touches.iter().next().is_some();
+
+    for (entity, scroll_view, interaction, node, transform, clip, visibility, mut state) in
+        views.iter_mut()
+    {
+        let new_state = if !is_visible(visibility, node) {
+            Interaction::None
+        } else if scroll_view.input.use_interaction {
+            match interaction.copied().unwrap_or(Interaction::None) {
+                Interaction::None if over_children.contains(&entity) => Interaction::Hovered,
+                interaction => interaction,
+            }
+        } else {
+            let rect = node.logical_rect(transform);
+            let rect = clip.map_or(rect, |clip| rect.intersect(clip.clip));
+            let hovered = cursor.is_some_and(|cursor| rect.contains(cursor))
+                || touches
+                    .iter()
+                    .any(|touch| rect.contains(touch.position() / ui_scale.0));
+            let pressed_here = (mouse.just_pressed(MouseButton::Left) && hovered)
+                || touches
+                    .iter_just_pressed()
+                    .any(|touch| rect.contains(touch.position() / ui_scale.0));
+            // Like `Interaction`, the view stays pressed until the pointer is released,
+            // even when it leaves the view.
+            if pressed_here || (state.0 == Interaction::Pressed && held) {
+                Interaction::Pressed
+            } else if hovered {
+                Interaction::Hovered
+            } else {
+                Interaction::None
+            }
+        };
+        if state.0 != new_state {
+            state.0 = new_state;
+        }
+    }
+}
+
+/// Returns false for views hidden by their or their ancestors' `Visibility`, or by
+/// `Display::None` which leaves the node without size.
+pub(crate) fn is_visible(visibility: Option<&InheritedVisibility>, node: &Node) -> bool {
+    visibility.is_none_or(|visibility| visibility.get()) && node.size() != Vec2::ZERO
+}
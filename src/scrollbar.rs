@@ -0,0 +1,431 @@
+use bevy::{input::mouse::MouseMotion, prelude::*, ui::FocusPolicy, window::PrimaryWindow};
+
+use crate::{
+    content_child, physical_pixel_scale, ScrollAxis, ScrollMetrics, ScrollView, ScrollableContent,
+};
+
+/// Opt-in component for the `ScrollView` entity, showing scrollbars for the axes
+/// the view scrolls on: a vertical one along the right edge and a horizontal one
+/// along the bottom edge. With both of them a corner filler is placed where they meet.
+///
+/// The thumb can be dragged and clicking the track moves the thumb to the cursor.
+/// Each scrollbar is hidden when the content does not overflow on its axis.
+/// The length of the thumb is proportional to the visible part of the content.
+#[derive(Component, Debug, Reflect, Clone)]
+pub struct Scrollbar {
+    /// Width of the scrollbar in pixels.
+    pub width: f32,
+    /// Color of the scrollbar track.
+    pub track_color: Color,
+    /// Color of the scrollbar thumb.
+    pub thumb_color: Color,
+    /// Minimum length of the thumb in pixels, so it stays usable with very long content.
+    pub min_thumb_size: f32,
+    /// Whether the scrollbars are drawn over the content or next to it.
+    pub layout: ScrollbarLayout,
+}
+
+/// Placement of the `Scrollbar` relative to the content.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ScrollbarLayout {
+    /// The scrollbars are drawn on top of the content and don't affect the layout, default.
+    #[default]
+    Overlay,
+    /// The scrollbars are placed in a gutter reserved by the padding of the view, so the
+    /// content is narrower and never sits under them. The gutter is reserved for every axis
+    /// the view scrolls on, also while the content fits, so the content does not jump
+    /// when a scrollbar appears.
+    /// The `right` and `bottom` padding of the `ScrollView` style are overwritten.
+    Inset,
+}
+
+impl Default for Scrollbar {
+    fn default() -> Self {
+        Self {
+            width: 8.0,
+            track_color: Color::srgba(0.0, 0.0, 0.0, 0.2),
+            thumb_color: Color::srgb(0.569, 0.592, 0.647),
+            min_thumb_size: 24.0,
+            layout: ScrollbarLayout::Overlay,
+        }
+    }
+}
+
+/// Marker of the scrollbar track node, spawned as a child of the `ScrollView`.
+#[derive(Component, Debug, Reflect)]
+pub struct ScrollbarTrack {
+    /// `ScrollView` entity the scrollbar belongs to.
+    pub view: Entity,
+    /// True for the horizontal scrollbar.
+    pub horizontal: bool,
+}
+
+/// Marker of the scrollbar thumb node, spawned as a child of the `ScrollbarTrack`.
+#[derive(Component, Debug, Reflect)]
+pub struct ScrollbarThumb {
+    /// `ScrollView` entity the scrollbar belongs to.
+    pub view: Entity,
+    /// True for the horizontal scrollbar.
+    pub horizontal: bool,
+}
+
+/// Marker of the node filling the corner between the vertical and horizontal scrollbar.
+#[derive(Component, Debug, Reflect)]
+pub struct ScrollbarCorner {
+    /// `ScrollView` entity the scrollbar belongs to.
+    pub view: Entity,
+}
+
+/// Spawns the scrollbar nodes for newly added `Scrollbar` components.
+pub fn spawn_scrollbar(
+    mut commands: Commands,
+    q: Query<(Entity, &Scrollbar, &ScrollView), Added<Scrollbar>>,
+) {
+    for (view, scrollbar, scroll_view) in q.iter() {
+        let mut spawned = Vec::with_capacity(3);
+        if scroll_view.axis.scrolls_vertically() {
+            spawned.push(spawn_track(&mut commands, view, scrollbar, false));
+        }
+        if scroll_view.axis.scrolls_horizontally() {
+            spawned.push(spawn_track(&mut commands, view, scrollbar, true));
+        }
+        if scroll_view.axis == ScrollAxis::Both {
+            let corner = commands
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            right: Val::Px(0.0),
+                            bottom: Val::Px(0.0),
+                            width: Val::Px(scrollbar.width),
+                            height: Val::Px(scrollbar.width),
+                            display: Display::None,
+                            ..default()
+                        },
+                        background_color: scrollbar.track_color.into(),
+                        ..default()
+                    },
+                    ScrollbarCorner { view },
+                ))
+                .id();
+            spawned.push(corner);
+        }
+        commands.entity(view).push_children(&spawned);
+    }
+}
+
+/// Reserves the gutter of the scrollbars with `ScrollbarLayout::Inset` and excludes it
+/// from the viewport of the view.
+pub fn update_scrollbar_layout(
+    mut q: Query<(&Scrollbar, &mut ScrollView, &mut Style), Changed<Scrollbar>>,
+) {
+    for (scrollbar, mut scroll_view, mut style) in q.iter_mut() {
+        let gutter = |scrolls: bool| {
+            if scrolls && scrollbar.layout == ScrollbarLayout::Inset {
+                scrollbar.width
+            } else {
+                0.0
+            }
+        };
+        // The vertical scrollbar takes the width, the horizontal one the height.
+        let inset = Vec2::new(
+            gutter(scroll_view.axis.scrolls_vertically()),
+            gutter(scroll_view.axis.scrolls_horizontally()),
+        );
+        if scroll_view.bounds.viewport_inset == inset {
+            continue;
+        }
+        scroll_view.bounds.viewport_inset = inset;
+        style.padding.right = Val::Px(inset.x);
+        style.padding.bottom = Val::Px(inset.y);
+    }
+}
+
+fn spawn_track(
+    commands: &mut Commands,
+    view: Entity,
+    scrollbar: &Scrollbar,
+    horizontal: bool,
+) -> Entity {
+    let (track_style, thumb_style) = if horizontal {
+        (
+            Style {
+                left: Val::Px(0.0),
+                height: Val::Px(scrollbar.width),
+                ..default()
+            },
+            Style {
+                height: Val::Percent(100.0),
+                ..default()
+            },
+        )
+    } else {
+        (
+            Style {
+                top: Val::Px(0.0),
+                width: Val::Px(scrollbar.width),
+                ..default()
+            },
+            Style {
+                width: Val::Percent(100.0),
+                ..default()
+            },
+        )
+    };
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(0.0),
+                    bottom: Val::Px(0.0),
+                    display: Display::None,
+                    ..track_style
+                },
+                background_color: scrollbar.track_color.into(),
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            ScrollbarTrack { view, horizontal },
+            Interaction::None,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        ..thumb_style
+                    },
+                    background_color: scrollbar.thumb_color.into(),
+                    focus_policy: FocusPolicy::Block,
+                    ..default()
+                },
+                ScrollbarThumb { view, horizontal },
+                Interaction::None,
+            ));
+        })
+        .id()
+}
+
+/// Returns the thumb length and the distance it can travel along the track.
+fn thumb_geometry(scrollbar: &Scrollbar, track_size: f32, max_scroll: f32) -> (f32, f32) {
+    let visible_ratio = track_size / (track_size + max_scroll);
+    let thumb_size = (track_size * visible_ratio)
+        .max(scrollbar.min_thumb_size)
+        .min(track_size);
+    (thumb_size, track_size - thumb_size)
+}
+
+/// Returns the value of the vector on the scrollbar axis.
+fn on_axis(value: Vec2, horizontal: bool) -> f32 {
+    if horizontal {
+        value.x
+    } else {
+        value.y
+    }
+}
+
+/// Returns the length of the scrollbar track, which is shorter when the other
+/// scrollbar is visible, because the corner is reserved.
+fn track_size(scrollbar: &Scrollbar, metrics: &ScrollMetrics, horizontal: bool) -> f32 {
+    let both_visible = metrics.max_scroll.x > 0.0 && metrics.max_scroll.y > 0.0;
+    let corner = if both_visible { scrollbar.width } else { 0.0 };
+    on_axis(metrics.container_size, horizontal) - corner
+}
+
+/// Updates the size and position of the scrollbar thumbs from the `ScrollMetrics`.
+pub fn update_scrollbar(
+    views: Query<
+        (&ScrollView, &Scrollbar, &ScrollMetrics),
+        Or<(Changed<ScrollMetrics>, Changed<Scrollbar>)>,
+    >,
+    mut tracks: Query<
+        (&ScrollbarTrack, &mut Style),
+        (Without<ScrollbarThumb>, Without<ScrollbarCorner>),
+    >,
+    mut thumbs: Query<
+        (&ScrollbarThumb, &mut Style),
+        (Without<ScrollbarTrack>, Without<ScrollbarCorner>),
+    >,
+    mut corners: Query<
+        (&ScrollbarCorner, &mut Style),
+        (Without<ScrollbarTrack>, Without<ScrollbarThumb>),
+    >,
+) {
+    for (track, mut style) in tracks.iter_mut() {
+        let Ok((_, scrollbar, metrics)) = views.get(track.view) else {
+            continue;
+        };
+        let display = if on_axis(metrics.max_scroll, track.horizontal) > 0.0 {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if style.display != display {
+            style.display = display;
+        }
+        // Leave space for the corner, so the scrollbars do not overlap.
+        let corner = Val::Px(
+            on_axis(metrics.container_size, track.horizontal)
+                - track_size(scrollbar, metrics, track.horizontal),
+        );
+        if track.horizontal && style.right != corner {
+            style.right = corner;
+        } else if !track.horizontal && style.bottom != corner {
+            style.bottom = corner;
+        }
+    }
+    for (thumb, mut style) in thumbs.iter_mut() {
+        let Ok((scroll_view, scrollbar, metrics)) = views.get(thumb.view) else {
+            continue;
+        };
+        let max_scroll = on_axis(metrics.max_scroll, thumb.horizontal);
+        let track_size = track_size(scrollbar, metrics, thumb.horizontal);
+        let (thumb_size, travel) = thumb_geometry(scrollbar, track_size, max_scroll);
+        let progress = if max_scroll > 0.0 {
+            -on_axis(metrics.offset, thumb.horizontal) / max_scroll
+        } else {
+            0.0
+        };
+        if thumb.horizontal {
+            // The thumb of right-to-left views starts on the right.
+            let progress = if scroll_view.right_to_left {
+                1.0 - progress
+            } else {
+                progress
+            };
+            style.width = Val::Px(thumb_size);
+            style.left = Val::Px(travel * progress);
+        } else {
+            style.height = Val::Px(thumb_size);
+            style.top = Val::Px(travel * progress);
+        }
+    }
+    for (corner, mut style) in corners.iter_mut() {
+        let Ok((_, _, metrics)) = views.get(corner.view) else {
+            continue;
+        };
+        let display = if metrics.max_scroll.x > 0.0 && metrics.max_scroll.y > 0.0 {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if style.display != display {
+            style.display = display;
+        }
+    }
+}
+
+/// Moves the content so the scrollbar thumb moves by `thumb_delta` pixels.
+fn move_by_thumb(
+    content_q: &mut Query<&mut ScrollableContent>,
+    children: &Children,
+    scrollbar: &Scrollbar,
+    metrics: &ScrollMetrics,
+    horizontal: bool,
+    thumb_delta: f32,
+) {
+    let max_scroll = on_axis(metrics.max_scroll, horizontal);
+    let track_size = track_size(scrollbar, metrics, horizontal);
+    let (_, travel) = thumb_geometry(scrollbar, track_size, max_scroll);
+    if travel <= 0.0 {
+        return;
+    }
+    let Some(child) = content_child(children, content_q) else {
+        return;
+    };
+    if let Ok(mut scroll) = content_q.get_mut(child) {
+        // Moving the thumb down moves the content up.
+        let pos = if horizontal {
+            &mut scroll.pos_x
+        } else {
+            &mut scroll.pos_y
+        };
+        *pos = (*pos - thumb_delta * max_scroll / travel).clamp(-max_scroll, 0.0);
+    }
+}
+
+/// Moves the content while a scrollbar thumb is dragged.
+pub fn scrollbar_thumb_drag(
+    mut motion_evr: EventReader<MouseMotion>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    thumbs: Query<(&ScrollbarThumb, &Interaction)>,
+    views: Query<(&ScrollView, &Scrollbar, &ScrollMetrics, &Children)>,
+    mut content_q: Query<&mut ScrollableContent>,
+) {
+    let delta: Vec2 = motion_evr.read().map(|ev| ev.delta).sum();
+    if delta == Vec2::ZERO {
+        return;
+    }
+    let delta = delta / physical_pixel_scale(windows.get_single().ok(), &ui_scale);
+    for (thumb, interaction) in thumbs.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some((scroll_view, scrollbar, metrics, children)) = views
+            .get(thumb.view)
+            .ok()
+            .filter(|(scroll_view, ..)| scroll_view.enabled)
+        else {
+            continue;
+        };
+        move_by_thumb(
+            &mut content_q,
+            children,
+            scrollbar,
+            metrics,
+            thumb.horizontal,
+            on_axis(scroll_view.physical_delta(delta), thumb.horizontal),
+        );
+    }
+}
+
+/// Clicking the track outside of the thumb centers the thumb on the cursor.
+pub fn scrollbar_track_click(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    tracks: Query<(&ScrollbarTrack, &Interaction, &Node, &GlobalTransform), Changed<Interaction>>,
+    thumbs: Query<(&ScrollbarThumb, &Node, &GlobalTransform)>,
+    views: Query<(&ScrollView, &Scrollbar, &ScrollMetrics, &Children)>,
+    mut content_q: Query<&mut ScrollableContent>,
+) {
+    let Some(cursor) = windows
+        .get_single()
+        .ok()
+        .and_then(Window::cursor_position)
+        .map(|p| p / ui_scale.0)
+    else {
+        return;
+    };
+    for (track, interaction, _, _) in tracks.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some((_, thumb_node, thumb_transform)) = thumbs
+            .iter()
+            .find(|(thumb, _, _)| thumb.view == track.view && thumb.horizontal == track.horizontal)
+        else {
+            continue;
+        };
+        let Some((scroll_view, scrollbar, metrics, children)) = views
+            .get(track.view)
+            .ok()
+            .filter(|(scroll_view, ..)| scroll_view.enabled)
+        else {
+            continue;
+        };
+        let thumb_center = thumb_node.logical_rect(thumb_transform).center();
+        move_by_thumb(
+            &mut content_q,
+            children,
+            scrollbar,
+            metrics,
+            track.horizontal,
+            on_axis(
+                scroll_view.physical_delta(cursor - thumb_center),
+                track.horizontal,
+            ),
+        );
+    }
+}
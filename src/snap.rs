@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+
+use crate::{
+    ScrollInteraction, ScrollLayoutCache, ScrollMomentum, ScrollView, ScrollableContent,
+    SmoothScroll,
+};
+
+/// Opt-in component for the `ScrollView` entity, snapping the content once the scrolling
+/// stops, so one of its children is aligned with the viewport.
+///
+/// The snap uses the `SmoothScroll` animation, so it composes with the smooth scrolling.
+#[derive(Component, Debug, Reflect, Clone)]
+pub struct ScrollSnap {
+    /// Which part of the child is aligned with the same part of the viewport.
+    pub snap_align: ScrollAnchor,
+    /// Approximate time in seconds of the snap animation, zero snaps instantly.
+    pub smooth_time: f32,
+}
+
+impl Default for ScrollSnap {
+    fn default() -> Self {
+        Self {
+            snap_align: ScrollAnchor::Start,
+            smooth_time: 0.15,
+        }
+    }
+}
+
+/// Alignment of a child within the viewport of the `ScrollView`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ScrollAnchor {
+    /// Start of the child at the start (top or left) of the viewport, common for lists.
+    #[default]
+    Start,
+    /// Center of the child at the center of the viewport, common for pickers.
+    Center,
+    /// End of the child at the end (bottom or right) of the viewport.
+    End,
+}
+
+impl ScrollAnchor {
+    /// Returns the content offset along the scroll axis aligning the child placed at
+    /// `child_offset` with `child_size` in the viewport of `viewport_size`.
+    pub fn offset_for(self, child_offset: f32, child_size: f32, viewport_size: f32) -> f32 {
+        match self {
+            ScrollAnchor::Start => -child_offset,
+            ScrollAnchor::Center => viewport_size / 2.0 - child_size / 2.0 - child_offset,
+            ScrollAnchor::End => viewport_size - child_size - child_offset,
+        }
+    }
+}
+
+/// Snaps the content of the views with `ScrollSnap` once the scrolling stops.
+pub fn snap_scroll(
+    mut commands: Commands,
+    views: Query<(
+        &ScrollView,
+        &ScrollSnap,
+        &Node,
+        &Children,
+        &ScrollInteraction,
+        &ScrollMomentum,
+    )>,
+    content_q: Query<(&ScrollableContent, &Node, &ScrollLayoutCache), Without<SmoothScroll>>,
+) {
+    for (scroll_view, snap, node, children, interaction, momentum) in views.iter() {
+        // Wait until the scrolling stops.
+        if interaction.0 == Interaction::Pressed || momentum.velocity != Vec2::ZERO {
+            continue;
+        }
+        for &child in children.iter() {
+            let Ok((scroll, content_node, cache)) = content_q.get(child) else {
+                continue;
+            };
+            let max_scroll = scroll_view.max_scroll(content_node.size(), node.size());
+            let leading = scroll_view.padding(node.size()).0;
+            let (pos, viewport, max_scroll, leading) = if cache.horizontal {
+                (scroll.pos_x, node.size().x, max_scroll.x, leading.x)
+            } else {
+                (scroll.pos_y, node.size().y, max_scroll.y, leading.y)
+            };
+            if max_scroll <= 0.0 {
+                continue;
+            }
+
+            let Some(target) = cache
+                .entries
+                .iter()
+                .map(|e| {
+                    let offset = snap
+                        .snap_align
+                        .offset_for(e.offset + leading, e.size, viewport);
+                    offset.clamp(-max_scroll, 0.0)
+                })
+                .min_by(|a, b| (a - pos).abs().total_cmp(&(b - pos).abs()))
+            else {
+                continue;
+            };
+            if (target - pos).abs() < 0.5 {
+                continue;
+            }
+            let target = if cache.horizontal {
+                Vec2::new(target, scroll.pos_y)
+            } else {
+                Vec2::new(scroll.pos_x, target)
+            };
+            commands
+                .entity(child)
+                .insert(SmoothScroll::to(target, snap.smooth_time));
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{content_child, is_laid_out, ScrollView, ScrollableContent, SmoothScroll};
+
+/// Scroll offsets of the `ScrollView`s identified by their `Name`, e.g. to remember
+/// where the user was between sessions. Views without a name are not captured, and
+/// the names should be unique.
+///
+/// It derives `Reflect`, so it can be saved and loaded with the Bevy reflection
+/// serializers together with the rest of the app state.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_scroll_view::*;
+///
+/// fn save(world: &mut World) {
+///     let snapshot = ScrollSnapshot::capture(world);
+///     // Store the snapshot, later restore it with `RestoreScroll(snapshot)`.
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct ScrollSnapshot {
+    /// Offsets of the content by the name of the view, same as `pos_x` and `pos_y`
+    /// of the `ScrollableContent`.
+    pub offsets: HashMap<String, Vec2>,
+}
+
+impl ScrollSnapshot {
+    /// Captures the offsets of all the named views in the `world`.
+    pub fn capture(world: &mut World) -> Self {
+        let mut views = world.query_filtered::<(&Name, &Children), With<ScrollView>>();
+        let offsets = views
+            .iter(world)
+            .filter_map(|(name, children)| {
+                let content = children
+                    .iter()
+                    .find_map(|&child| world.get::<ScrollableContent>(child))?;
+                Some((name.to_string(), Vec2::new(content.pos_x, content.pos_y)))
+            })
+            .collect();
+        Self { offsets }
+    }
+}
+
+/// Event restoring the offsets of the `ScrollSnapshot` to the views with the same `Name`.
+/// Each offset is kept in the range allowed by the current size of the content.
+///
+/// Views which are not spawned or laid out yet are restored once the layout computes
+/// their size, so the snapshot can be restored together with spawning the UI. The offsets
+/// of views which are still missing three frames later are dropped.
+#[derive(Event, Debug, Clone)]
+pub struct RestoreScroll(pub ScrollSnapshot);
+
+/// Number of frames `RestoreScroll` waits for the views which are not laid out yet.
+const RESTORE_FRAMES: u32 = 3;
+
+/// Handles the `RestoreScroll` events.
+pub fn restore_scroll(
+    mut commands: Commands,
+    mut events: EventReader<RestoreScroll>,
+    mut pending: Local<HashMap<String, (Vec2, u32)>>,
+    views: Query<(&Name, &ScrollView, &Node, &Children)>,
+    mut content_q: Query<(&mut ScrollableContent, &Node)>,
+) {
+    for ev in events.read() {
+        pending.extend(
+            ev.0.offsets
+                .iter()
+                .map(|(name, &offset)| (name.clone(), (offset, RESTORE_FRAMES))),
+        );
+    }
+    if pending.is_empty() {
+        return;
+    }
+    for (name, scroll_view, node, children) in views.iter() {
+        if !is_laid_out(node) {
+            continue;
+        }
+        let Some((offset, _)) = pending.remove(name.as_str()) else {
+            continue;
+        };
+        let Some(child) = content_child(children, &content_q) else {
+            continue;
+        };
+        let Ok((mut scroll, content_node)) = content_q.get_mut(child) else {
+            continue;
+        };
+        let pos = scroll_view.constrain(offset, content_node.size(), node.size());
+        scroll.pos_x = pos.x;
+        scroll.pos_y = pos.y;
+        commands.entity(child).remove::<SmoothScroll>();
+    }
+    // A view spawned much later with the same name should not jump to the old offset.
+    pending.retain(|_, (_, frames)| {
+        *frames -= 1;
+        *frames > 0
+    });
+}
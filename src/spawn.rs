@@ -0,0 +1,171 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use crate::{ScrollAxis, ScrollView, ScrollableContent};
+
+/// Extension of `Commands` and `ChildBuilder` spawning a `ScrollView` together with its
+/// `ScrollableContent`, so the structure required by the plugin can't be set up wrong.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_scroll_view::*;
+///
+/// fn spawn_list(mut commands: Commands) {
+///     let view = NodeBundle {
+///         style: Style {
+///             height: Val::Px(300.0),
+///             ..default()
+///         },
+///         ..default()
+///     };
+///     commands.spawn_scroll_view(view, ScrollView::default(), |content| {
+///         for i in 0..20 {
+///             content.spawn(TextBundle::from_section(format!("Item {i}"), default()));
+///         }
+///     });
+/// }
+/// ```
+pub trait SpawnScrollView {
+    /// Spawns the `view` node with the `scroll_view` and a `ScrollableContent` child laid out
+    /// along the scroll axis, then calls `content` to spawn the children of the content.
+    /// Returns the `ScrollView` and `ScrollableContent` entities.
+    fn spawn_scroll_view(
+        &mut self,
+        view: NodeBundle,
+        scroll_view: ScrollView,
+        content: impl FnOnce(&mut ChildBuilder),
+    ) -> (Entity, Entity);
+}
+
+impl SpawnScrollView for Commands<'_, '_> {
+    fn spawn_scroll_view(
+        &mut self,
+        view: NodeBundle,
+        scroll_view: ScrollView,
+        content: impl FnOnce(&mut ChildBuilder),
+    ) -> (Entity, Entity) {
+        let mut view = self.spawn(view);
+        let content = spawn_content(&mut view, scroll_view.axis, content);
+        (view.insert(scroll_view).id(), content)
+    }
+}
+
+impl SpawnScrollView for ChildBuilder<'_> {
+    fn spawn_scroll_view(
+        &mut self,
+        view: NodeBundle,
+        scroll_view: ScrollView,
+        content: impl FnOnce(&mut ChildBuilder),
+    ) -> (Entity, Entity) {
+        let mut view = self.spawn(view);
+        let content = spawn_content(&mut view, scroll_view.axis, content);
+        (view.insert(scroll_view).id(), content)
+    }
+}
+
+fn spawn_content(
+    view: &mut EntityCommands,
+    axis: ScrollAxis,
+    content: impl FnOnce(&mut ChildBuilder),
+) -> Entity {
+    let mut entity = Entity::PLACEHOLDER;
+    view.with_children(|p| {
+        entity = p
+            .spawn(ScrollableContentBundle::new(axis))
+            .with_children(content)
+            .id();
+    });
+    entity
+}
+
+/// Bundle of a `ScrollView` node clipping its content.
+///
+/// The `ScrollableContent` is spawned as its child, e.g. with `ScrollableContentBundle`
+/// using the same axis:
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_simple_scroll_view::*;
+///
+/// fn spawn_list(mut commands: Commands) {
+///     commands
+///         .spawn(ScrollViewBundle::new(ScrollView::default()))
+///         .with_children(|p| {
+///             p.spawn(ScrollableContentBundle::new(ScrollAxis::Vertical));
+///         });
+/// }
+/// ```
+#[derive(Bundle)]
+pub struct ScrollViewBundle {
+    /// Node of the view, its size is the viewport of the content.
+    pub node: NodeBundle,
+    /// Options of the view.
+    pub scroll_view: ScrollView,
+}
+
+impl ScrollViewBundle {
+    /// Creates the bundle with the given `scroll_view` and the default node.
+    pub fn new(scroll_view: ScrollView) -> Self {
+        Self {
+            scroll_view,
+            ..default()
+        }
+    }
+}
+
+impl Default for ScrollViewBundle {
+    fn default() -> Self {
+        Self {
+            node: NodeBundle {
+                style: Style {
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                ..default()
+            },
+            scroll_view: ScrollView::default(),
+        }
+    }
+}
+
+/// Bundle of a `ScrollableContent` node, the child of the `ScrollView`.
+/// The default is laid out for a vertical view.
+#[derive(Bundle)]
+pub struct ScrollableContentBundle {
+    /// Node of the content, laid out along the scroll axis.
+    pub node: NodeBundle,
+    /// Offset of the content.
+    pub content: ScrollableContent,
+}
+
+impl ScrollableContentBundle {
+    /// Creates the bundle with the layout for a view scrolling on the given `axis`.
+    pub fn new(axis: ScrollAxis) -> Self {
+        // The content fills the view across the scroll axis and grows along it.
+        let style = match axis {
+            ScrollAxis::Vertical => Style {
+                flex_direction: FlexDirection::Column,
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ScrollAxis::Horizontal => Style {
+                flex_direction: FlexDirection::Row,
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            ScrollAxis::Both => Style {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+        };
+        Self {
+            node: NodeBundle { style, ..default() },
+            content: ScrollableContent::default(),
+        }
+    }
+}
+
+impl Default for ScrollableContentBundle {
+    fn default() -> Self {
+        Self::new(ScrollAxis::Vertical)
+    }
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "accessibility")]
+
+mod common;
+
+use bevy::{
+    a11y::{accesskit::Role, AccessibilityNode},
+    prelude::*,
+};
+use bevy_simple_scroll_view::*;
+use common::*;
+
+#[test]
+fn accessibility_node_reports_the_scroll_position() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.set_offset(v.content, Vec2::new(0.0, -100.0));
+    h.update();
+
+    let world = h.app.world();
+    let metrics = world.get::<ScrollMetrics>(v.view).unwrap();
+    let node = world.get::<AccessibilityNode>(v.view).unwrap();
+    assert_eq!(node.role(), Role::ScrollView);
+    assert_eq!(node.scroll_y(), Some(-metrics.offset.y as f64));
+    assert_eq!(node.scroll_y(), Some(100.0));
+    assert_eq!(node.scroll_y_min(), Some(0.0));
+    assert_eq!(node.scroll_y_max(), Some(metrics.max_scroll.y as f64));
+    assert_eq!(node.scroll_y_max(), Some(400.0));
+}
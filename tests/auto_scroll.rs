@@ -0,0 +1,98 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+fn auto_scrolling_view(h: &mut Harness, velocity: f32, mode: AutoScrollMode) -> TestView {
+    let v = vertical_view(h, ScrollView::default());
+    h.app
+        .world_mut()
+        .entity_mut(v.view)
+        .insert(AutoScroll::new(Vec2::new(0.0, velocity), mode));
+    h.settle();
+    v
+}
+
+#[test]
+fn auto_scroll_moves_the_content_with_the_velocity() {
+    let mut h = Harness::new();
+    let v = auto_scrolling_view(&mut h, 60.0, AutoScrollMode::Stop);
+    let start = h.offset(v.content).y;
+
+    h.run(60);
+    assert!((h.offset(v.content).y - (start - 60.0)).abs() < 0.01);
+}
+
+#[test]
+fn auto_scroll_stops_at_the_end() {
+    let mut h = Harness::new();
+    let v = auto_scrolling_view(&mut h, 6000.0, AutoScrollMode::Stop);
+
+    h.run(10);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+}
+
+#[test]
+fn auto_scroll_loops_back_to_the_start() {
+    let mut h = Harness::new();
+    let v = auto_scrolling_view(&mut h, 6000.0, AutoScrollMode::Loop);
+    h.set_offset(v.content, Vec2::new(0.0, -399.0));
+
+    h.update();
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
+
+#[test]
+fn auto_scroll_reverses_at_the_end() {
+    let mut h = Harness::new();
+    let v = auto_scrolling_view(&mut h, 600.0, AutoScrollMode::Reverse);
+    h.set_offset(v.content, Vec2::new(0.0, -399.0));
+
+    h.update();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+    let auto = h.app.world().get::<AutoScroll>(v.view).unwrap();
+    assert_eq!(auto.velocity, Vec2::new(0.0, -600.0));
+    h.update();
+    assert!(h.offset(v.content).y > -400.0);
+}
+
+#[test]
+fn user_input_pauses_auto_scroll() {
+    let mut h = Harness::new();
+    let v = auto_scrolling_view(&mut h, 60.0, AutoScrollMode::Stop);
+    h.hover(h.center_of(v.view));
+
+    h.wheel_lines(0.0, -1.0);
+    let after_input = h.offset(v.content);
+    h.run(60);
+    assert_eq!(h.offset(v.content), after_input);
+    h.run(70);
+    assert!(h.offset(v.content).y < after_input.y);
+}
+
+#[test]
+fn edge_auto_scroll_scrolls_while_dragging_near_the_edge() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.app
+        .world_mut()
+        .entity_mut(v.view)
+        .insert(EdgeAutoScroll::default());
+    h.settle();
+
+    // 10 pixels from the bottom edge, three quarters into the margin.
+    h.hover(h.center_of(v.view) + Vec2::new(0.0, 40.0));
+    h.run(10);
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+
+    h.app.insert_resource(EdgeAutoScrollActive(true));
+    h.run(10);
+    let expected = -600.0 * 0.75 * FRAME * 10.0;
+    assert!((h.offset(v.content).y - expected).abs() < 0.01);
+
+    h.hover(h.center_of(v.view));
+    let before = h.offset(v.content);
+    h.run(10);
+    assert_eq!(h.offset(v.content), before);
+}
@@ -0,0 +1,450 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+fn view_with_mode(h: &mut Harness, boundary_mode: BoundaryMode) -> TestView {
+    let v = vertical_view(
+        h,
+        ScrollView {
+            bounds: ScrollBounds {
+                boundary_mode,
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+    v
+}
+
+#[test]
+fn clamp_mode_stops_at_the_ends() {
+    let mut h = Harness::new();
+    let v = view_with_mode(&mut h, BoundaryMode::Clamp);
+
+    h.wheel_lines(0.0, 1.0);
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+    h.wheel_lines(0.0, -20.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+}
+
+#[test]
+fn free_mode_scrolls_past_the_ends() {
+    let mut h = Harness::new();
+    let v = view_with_mode(&mut h, BoundaryMode::Free);
+
+    h.wheel_lines(0.0, 1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, 40.0));
+    h.wheel_lines(0.0, -20.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -760.0));
+}
+
+#[test]
+fn free_mode_consumes_the_input_with_propagation() {
+    let mut h = Harness::new();
+    let root = h.root;
+    let outer = h.spawn_view(root, ScrollView::default(), Vec2::new(300.0, 200.0), 0, 0.0);
+    let inner = h.spawn_view(
+        outer.content,
+        ScrollView {
+            propagate: ScrollPropagation::AtBoundary,
+            bounds: ScrollBounds {
+                boundary_mode: BoundaryMode::Free,
+                ..default()
+            },
+            ..default()
+        },
+        Vec2::new(200.0, 100.0),
+        3,
+        50.0,
+    );
+    h.spawn_items(outer.content, 10, 50.0, false);
+    h.settle();
+    h.hover(h.center_of(inner.view));
+
+    h.wheel_lines(0.0, -5.0);
+    assert_eq!(h.offset(inner.content), Vec2::new(0.0, -200.0));
+    assert_eq!(h.offset(outer.content), Vec2::ZERO);
+}
+
+#[test]
+fn wrap_mode_wraps_around_the_content_size() {
+    let mut h = Harness::new();
+    let v = view_with_mode(&mut h, BoundaryMode::Wrap);
+
+    h.wheel_lines(0.0, 1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -460.0));
+    h.wheel_lines(0.0, -12.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -460.0 + 20.0));
+}
+
+#[test]
+fn shrinking_content_clamps_the_offset() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.set_offset(v.content, Vec2::new(0.0, -400.0));
+    h.update();
+
+    let children: Vec<Entity> = h
+        .app
+        .world()
+        .get::<Children>(v.content)
+        .unwrap()
+        .iter()
+        .copied()
+        .collect();
+    for child in &children[5..] {
+        h.app.world_mut().entity_mut(*child).despawn_recursive();
+    }
+    h.settle();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -150.0));
+}
+
+#[test]
+fn hiding_items_clamps_the_offset() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.set_offset(v.content, Vec2::new(0.0, -400.0));
+    h.update();
+
+    let children: Vec<Entity> = h
+        .app
+        .world()
+        .get::<Children>(v.content)
+        .unwrap()
+        .iter()
+        .copied()
+        .collect();
+    for child in children.iter().step_by(2) {
+        h.app.world_mut().get_mut::<Style>(*child).unwrap().display = Display::None;
+    }
+    h.settle();
+    let metrics = *h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.max_scroll, Vec2::new(0.0, 150.0));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -150.0));
+}
+
+#[test]
+fn growing_then_shrinking_content_leaves_no_gap() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.spawn_items(v.content, 10, 50.0, false);
+    h.settle();
+    h.set_offset(v.content, Vec2::new(0.0, -900.0));
+    h.settle();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -900.0));
+
+    let children: Vec<Entity> = h
+        .app
+        .world()
+        .get::<Children>(v.content)
+        .unwrap()
+        .iter()
+        .copied()
+        .collect();
+    for child in &children[3..] {
+        h.app.world_mut().entity_mut(*child).despawn_recursive();
+    }
+    h.settle();
+    // The last item ends at the bottom of the view.
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -50.0));
+}
+
+#[test]
+fn max_height_limits_the_viewport_from_the_first_layout() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    {
+        let mut style = h.app.world_mut().get_mut::<Style>(v.view).unwrap();
+        style.height = Val::Auto;
+        style.max_height = Val::Px(100.0);
+    }
+    h.set_offset(v.content, Vec2::new(0.0, -1000.0));
+
+    // The first frame computes the layout, the next one uses it.
+    h.run(2);
+    let metrics = *h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.container_size, Vec2::new(200.0, 100.0));
+    assert_eq!(metrics.max_scroll, Vec2::new(0.0, 400.0));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+
+    h.app
+        .world_mut()
+        .get_mut::<Style>(v.view)
+        .unwrap()
+        .max_height = Val::Px(200.0);
+    h.run(2);
+    let metrics = *h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.max_scroll, Vec2::new(0.0, 300.0));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -300.0));
+}
+
+#[test]
+fn growing_view_clamps_the_offset() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.set_offset(v.content, Vec2::new(0.0, -400.0));
+    h.update();
+
+    h.app.world_mut().get_mut::<Style>(v.view).unwrap().height = Val::Px(300.0);
+    h.settle();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -200.0));
+}
+
+#[test]
+fn stick_to_bottom_follows_growing_content_only_from_the_bottom() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.app.world_mut().entity_mut(v.view).insert(StickToBottom);
+    h.settle();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+
+    h.spawn_items(v.content, 1, 50.0, false);
+    h.settle();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -450.0));
+
+    h.set_offset(v.content, Vec2::new(0.0, -100.0));
+    h.update();
+    h.spawn_items(v.content, 2, 50.0, false);
+    h.settle();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -100.0));
+}
+
+#[test]
+fn scroll_anchoring_keeps_the_view_when_content_is_prepended() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.app.world_mut().entity_mut(v.view).insert(ScrollAnchoring);
+    h.settle();
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+    h.set_offset(v.content, Vec2::new(0.0, -100.0));
+    h.update();
+
+    let older = h
+        .app
+        .world_mut()
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Px(50.0),
+                flex_shrink: 0.0,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    h.app
+        .world_mut()
+        .entity_mut(v.content)
+        .insert_children(0, &[older]);
+    h.settle();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -150.0));
+}
+
+#[test]
+fn metrics_follow_the_content_size_without_input() {
+    let mut h = Harness::new();
+    let root = h.root;
+    let v = h.spawn_view(
+        root,
+        ScrollView::default(),
+        Vec2::new(200.0, 100.0),
+        2,
+        50.0,
+    );
+    h.settle();
+    let metrics = *h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.max_scroll, Vec2::ZERO);
+    assert!(!metrics.is_scrollable());
+
+    h.spawn_items(v.content, 3, 50.0, false);
+    h.settle();
+    let metrics = *h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.max_scroll, Vec2::new(0.0, 150.0));
+    assert!(metrics.is_scrollable());
+}
+
+#[test]
+fn pixel_snap_rounds_only_the_applied_position() {
+    let mut h = Harness::new();
+    let window = h.window;
+    let v = vertical_view(
+        &mut h,
+        ScrollView {
+            pixel_snap: true,
+            ..default()
+        },
+    );
+    h.settle();
+
+    h.set_offset(v.content, Vec2::new(0.0, -10.3));
+    h.update();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -10.3));
+    let style = h.app.world().get::<Style>(v.content).unwrap();
+    assert_eq!(style.top, Val::Px(-10.0));
+
+    h.app
+        .world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .resolution
+        .set_scale_factor_override(Some(2.0));
+    h.set_offset(v.content, Vec2::new(0.0, -10.3));
+    h.update();
+    let style = h.app.world().get::<Style>(v.content).unwrap();
+    assert_eq!(style.top, Val::Px(-10.5));
+}
+
+#[test]
+fn scroll_view_on_non_ui_entity_does_not_crash() {
+    let mut h = Harness::new();
+    let view = h.app.world_mut().spawn(ScrollView::default()).id();
+    h.app
+        .world_mut()
+        .spawn(ScrollableContent::default())
+        .set_parent(view);
+    h.settle();
+    h.wheel_lines(0.0, -1.0);
+    h.press();
+    h.drag(Vec2::new(0.0, -10.0));
+    h.release();
+}
+
+fn view_with_padding(h: &mut Harness, content_padding: (Val, Val)) -> TestView {
+    let v = vertical_view(
+        h,
+        ScrollView {
+            bounds: ScrollBounds {
+                content_padding,
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    v
+}
+
+#[test]
+fn percent_padding_is_relative_to_the_view_size() {
+    let mut h = Harness::new();
+    let v = view_with_padding(&mut h, (Val::Percent(50.0), Val::Percent(50.0)));
+
+    let metrics = h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.max_scroll, Vec2::new(0.0, 500.0));
+    let style = h.app.world().get::<Style>(v.content).unwrap();
+    assert_eq!(style.top, Val::Px(50.0));
+}
+
+#[test]
+fn viewport_units_are_relative_to_the_view() {
+    let mut h = Harness::new();
+    let v = view_with_padding(&mut h, (Val::Vh(20.0), Val::Px(30.0)));
+
+    let metrics = h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.max_scroll, Vec2::new(0.0, 450.0));
+}
+
+#[test]
+fn pixel_padding_extends_the_clamp_range_on_both_ends() {
+    let mut h = Harness::new();
+    let v = view_with_padding(&mut h, (Val::Px(20.0), Val::Px(30.0)));
+    h.hover(h.center_of(v.view));
+
+    // The last item stops 30 pixels above the bottom of the view.
+    h.wheel_pixels(0.0, -1000.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -450.0));
+    h.update();
+    let style = h.app.world().get::<Style>(v.content).unwrap();
+    assert_eq!(style.top, Val::Px(-430.0));
+
+    // The first item stops 20 pixels below the top of the view.
+    h.wheel_pixels(0.0, 1000.0);
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+    h.update();
+    let style = h.app.world().get::<Style>(v.content).unwrap();
+    assert_eq!(style.top, Val::Px(20.0));
+}
+
+#[test]
+fn auto_padding_is_zero() {
+    let mut h = Harness::new();
+    let v = view_with_padding(&mut h, (Val::Auto, Val::Auto));
+
+    let metrics = h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.max_scroll, Vec2::new(0.0, 400.0));
+}
+
+#[test]
+fn progress_reaches_one_at_the_end() {
+    let mut h = Harness::new();
+    let v = view_with_padding(&mut h, (Val::Px(20.0), Val::Px(30.0)));
+
+    let progress = |h: &Harness| {
+        let world = h.app.world();
+        world.get::<ScrollableContent>(v.content).unwrap().progress(
+            world.get::<ScrollView>(v.view).unwrap(),
+            world.get::<Node>(v.content).unwrap(),
+            world.get::<Node>(v.view).unwrap(),
+        )
+    };
+    assert_eq!(progress(&h), Vec2::ZERO);
+
+    h.set_offset(v.content, Vec2::new(0.0, -225.0));
+    assert_eq!(progress(&h), Vec2::new(0.0, 0.5));
+
+    h.hover(h.center_of(v.view));
+    h.wheel_lines(0.0, -100.0);
+    assert_eq!(progress(&h), Vec2::new(0.0, 1.0));
+    let metrics = h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.progress(), Vec2::new(0.0, 1.0));
+}
+
+#[test]
+fn apply_scroll_delta_reports_the_outcome() {
+    let scroll_view = ScrollView::default().with_propagate(true);
+    let mut content = ScrollableContent::default();
+    let (content_size, container_size) = (Vec2::new(200.0, 500.0), Vec2::new(200.0, 100.0));
+
+    let outcome = apply_scroll_delta(
+        &scroll_view,
+        &mut content,
+        content_size,
+        container_size,
+        Vec2::new(0.0, -150.0),
+    );
+    assert_eq!(
+        outcome,
+        ScrollOutcome {
+            applied: true,
+            at_boundary: false,
+            remaining: Vec2::ZERO,
+            consumed: true,
+        }
+    );
+    assert_eq!(content.pos_y, -150.0);
+
+    let outcome = apply_scroll_delta(
+        &scroll_view,
+        &mut content,
+        content_size,
+        container_size,
+        Vec2::new(0.0, -1000.0),
+    );
+    assert_eq!(
+        outcome,
+        ScrollOutcome {
+            applied: false,
+            at_boundary: true,
+            remaining: Vec2::new(0.0, -750.0),
+            consumed: false,
+        }
+    );
+    assert_eq!(content.pos_y, -400.0);
+}
@@ -0,0 +1,362 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+#[test]
+fn scroll_by_pages_moves_by_the_page_fraction_of_the_view() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.send(ScrollBy::pages(v.view, 1.0));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -90.0));
+    h.send(ScrollBy::pages(v.view, -0.5));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -45.0));
+}
+
+#[test]
+fn scroll_by_lines_moves_by_the_line_height() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.send(ScrollBy::lines(v.view, 3.0, 20.0));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -60.0));
+}
+
+#[test]
+fn scroll_by_is_clamped() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.send(ScrollBy::pages(v.view, 10.0));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+    h.send(ScrollBy::pixels(v.view, Vec2::new(0.0, -1000.0)));
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
+
+#[test]
+fn animated_scroll_by_reaches_the_target() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.send(ScrollBy::pages(v.view, 1.0).animated(0.1));
+    let first_frame = h.offset(v.content).y;
+    assert!(first_frame > -90.0);
+    h.run(60);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -90.0));
+}
+
+#[test]
+fn scroll_to_moves_to_the_clamped_position() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.send(ScrollTo::new(v.view, Vec2::new(50.0, 120.0)));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -120.0));
+    h.send(ScrollTo::new(v.view, Vec2::INFINITY));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+    h.send(ScrollTo::new(v.view, Vec2::new(0.0, -10.0)));
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
+
+#[test]
+fn scroll_to_progress_is_a_fraction_of_the_range() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.send(ScrollTo::progress(v.view, Vec2::new(0.5, 0.25)));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -100.0));
+    h.send(ScrollTo::progress(v.view, Vec2::splat(2.0)));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+    h.send(ScrollTo::progress(v.view, Vec2::splat(-1.0)));
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
+
+#[test]
+fn animated_scroll_to_reaches_the_target() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.send(ScrollTo::new(v.view, Vec2::new(0.0, 200.0)).animated(0.1));
+    assert!(h.offset(v.content).y > -200.0);
+    h.run(60);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -200.0));
+}
+
+#[test]
+fn scroll_to_end_lands_on_the_last_offset() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.send(ScrollTo::end(v.view));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+    h.send(ScrollTo::start(v.view));
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
+
+#[test]
+fn scroll_to_end_keeps_fitting_content_in_place() {
+    let mut h = Harness::new();
+    let root = h.root;
+    let v = h.spawn_view(
+        root,
+        ScrollView::default(),
+        Vec2::new(200.0, 100.0),
+        1,
+        50.0,
+    );
+    h.settle();
+
+    h.send(ScrollTo::end(v.view));
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
+
+#[test]
+fn scroll_to_end_in_free_mode_stops_at_the_end() {
+    let mut h = Harness::new();
+    let v = vertical_view(
+        &mut h,
+        ScrollView {
+            bounds: ScrollBounds {
+                boundary_mode: BoundaryMode::Free,
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+
+    h.send(ScrollTo::end(v.view));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+}
+
+fn item(h: &Harness, v: &TestView, index: usize) -> Entity {
+    h.app.world().get::<Children>(v.content).unwrap()[index]
+}
+
+#[test]
+fn scroll_to_child_aligns_the_child() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.send(ScrollToChild::new(item(&h, &v, 4)));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -200.0));
+    h.send(ScrollToChild::new(item(&h, &v, 5)).aligned(ScrollAnchor::Center));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -225.0));
+    h.send(ScrollToChild::new(item(&h, &v, 9)));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+}
+
+#[test]
+fn scroll_to_child_finds_children_nested_in_wrappers() {
+    let mut h = Harness::new();
+    let root = h.root;
+    let v = h.spawn_view(
+        root,
+        ScrollView::default(),
+        Vec2::new(200.0, 100.0),
+        2,
+        50.0,
+    );
+    let wrapper = h
+        .app
+        .world_mut()
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                width: Val::Percent(100.0),
+                padding: UiRect::top(Val::Px(20.0)),
+                flex_shrink: 0.0,
+                ..default()
+            },
+            ..default()
+        })
+        .set_parent(v.content)
+        .id();
+    h.spawn_items(wrapper, 3, 50.0, false);
+    h.spawn_items(v.content, 4, 50.0, false);
+    h.settle();
+
+    // Two items above the wrapper, its padding and the first item in it.
+    let target = h.app.world().get::<Children>(wrapper).unwrap()[1];
+    h.send(ScrollToChild::new(target));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -170.0));
+}
+
+#[test]
+fn scroll_to_child_scrolls_the_innermost_view() {
+    let mut h = Harness::new();
+    let outer = vertical_view(&mut h, ScrollView::default());
+    let inner = h.spawn_view(
+        outer.content,
+        ScrollView::default(),
+        Vec2::new(200.0, 100.0),
+        10,
+        50.0,
+    );
+    h.settle();
+
+    h.send(ScrollToChild::new(item(&h, &inner, 4)));
+    assert_eq!(h.offset(inner.content), Vec2::new(0.0, -200.0));
+    assert_eq!(h.offset(outer.content), Vec2::ZERO);
+}
+
+#[test]
+fn scroll_child_into_view_moves_the_least_distance() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.set_offset(v.content, Vec2::new(0.0, -200.0));
+    h.update();
+
+    // Fully visible children stay in place.
+    h.send(ScrollToChild::into_view(item(&h, &v, 5)));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -200.0));
+    // A child below the viewport ends at its bottom.
+    h.send(ScrollToChild::into_view(item(&h, &v, 6)));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -250.0));
+    // A child above the viewport starts at its top.
+    h.send(ScrollToChild::into_view(item(&h, &v, 1)));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -50.0));
+}
+
+#[derive(Resource)]
+struct Observed {
+    view: TestView,
+    offset: Vec2,
+}
+
+#[test]
+fn user_systems_can_be_ordered_around_the_scroll_sets() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.app
+        .insert_resource(Observed {
+            view: v,
+            offset: Vec2::ZERO,
+        })
+        .add_systems(
+            Update,
+            (
+                (|o: Res<Observed>, mut scroll_by: EventWriter<ScrollBy>| {
+                    scroll_by.send(ScrollBy::pixels(o.view.view, Vec2::new(0.0, 10.0)));
+                })
+                .before(ScrollSystem::Events),
+                (|mut o: ResMut<Observed>, q: Query<&ScrollableContent>| {
+                    let content = q.get(o.view.content).unwrap();
+                    o.offset = Vec2::new(content.pos_x, content.pos_y);
+                })
+                .after(ScrollSystem::Update),
+            ),
+        );
+    h.update();
+
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -10.0));
+    assert_eq!(
+        h.app.world().resource::<Observed>().offset,
+        Vec2::new(0.0, -10.0)
+    );
+}
+
+#[test]
+fn snapshot_restores_the_offsets_by_name() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.app
+        .world_mut()
+        .entity_mut(v.view)
+        .insert(Name::new("list"));
+    h.settle();
+    h.set_offset(v.content, Vec2::new(0.0, -100.0));
+    h.update();
+
+    let snapshot = ScrollSnapshot::capture(h.app.world_mut());
+    assert_eq!(snapshot.offsets["list"], Vec2::new(0.0, -100.0));
+
+    h.set_offset(v.content, Vec2::ZERO);
+    h.send(RestoreScroll(snapshot.clone()));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -100.0));
+
+    let mut too_far = snapshot;
+    too_far
+        .offsets
+        .insert("list".to_string(), Vec2::new(0.0, -1000.0));
+    h.send(RestoreScroll(too_far));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+}
+
+#[test]
+fn commands_move_only_the_first_content() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    let second = h
+        .app
+        .world_mut()
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(100.0),
+                    ..default()
+                },
+                ..default()
+            },
+            ScrollableContent::default(),
+        ))
+        .set_parent(v.view)
+        .id();
+    h.spawn_items(second, 10, 50.0, false);
+    h.settle();
+
+    h.send(ScrollBy::pixels(v.view, Vec2::new(0.0, 30.0)));
+    h.send(ScrollTo::new(v.view, Vec2::new(0.0, 60.0)));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -60.0));
+    assert_eq!(h.offset(second), Vec2::ZERO);
+}
+
+#[test]
+fn restore_waits_for_the_view_spawned_in_the_same_frame() {
+    let mut h = Harness::new();
+    let offsets = [("list".to_string(), Vec2::new(0.0, -100.0))]
+        .into_iter()
+        .collect();
+    h.app
+        .world_mut()
+        .send_event(RestoreScroll(ScrollSnapshot { offsets }));
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.app
+        .world_mut()
+        .entity_mut(v.view)
+        .insert(Name::new("list"));
+    h.settle();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -100.0));
+}
+
+#[test]
+fn restore_drops_the_offsets_of_views_which_are_not_spawned() {
+    let mut h = Harness::new();
+    let offsets = [("list".to_string(), Vec2::new(0.0, -100.0))]
+        .into_iter()
+        .collect();
+    h.send(RestoreScroll(ScrollSnapshot { offsets }));
+    h.run(10);
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.app
+        .world_mut()
+        .entity_mut(v.view)
+        .insert(Name::new("list"));
+    h.settle();
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
@@ -0,0 +1,358 @@
+//! Headless harness for the integration tests.
+//!
+//! It runs the full UI layout on top of `MinimalPlugins`, without a window, GPU or audio,
+//! and feeds synthetic input events, so the scroll systems see the same `Node` sizes
+//! and `Interaction` states as in a real app.
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use bevy::{
+    asset::AssetPlugin,
+    input::{
+        keyboard::{Key, KeyboardInput, NativeKey},
+        mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
+        touch::{TouchInput, TouchPhase},
+        ButtonState, InputPlugin,
+    },
+    prelude::*,
+    render::{
+        camera::CameraPlugin, render_resource::Shader, texture::ImagePlugin, view::VisibilityPlugin,
+    },
+    text::TextPlugin,
+    time::TimeUpdateStrategy,
+    ui::UiPlugin,
+    window::{PrimaryWindow, WindowResolution},
+};
+use bevy_simple_scroll_view::*;
+
+/// Duration of a single frame.
+pub const FRAME: f32 = 1.0 / 60.0;
+
+/// Entities of a scroll view spawned by `Harness::spawn_view`.
+#[derive(Debug, Clone, Copy)]
+pub struct TestView {
+    pub view: Entity,
+    pub content: Entity,
+}
+
+pub struct Harness {
+    pub app: App,
+    pub window: Entity,
+    pub root: Entity,
+    touch_position: Vec2,
+}
+
+impl Harness {
+    /// Creates the app with a 800x600 window, a camera and a root node filling the window.
+    pub fn new() -> Self {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            HierarchyPlugin,
+            InputPlugin,
+            WindowPlugin {
+                primary_window: Some(Window {
+                    resolution: WindowResolution::new(800.0, 600.0).with_scale_factor_override(1.0),
+                    ..default()
+                }),
+                ..default()
+            },
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+            CameraPlugin,
+            VisibilityPlugin,
+        ))
+        // The UI plugin loads its shaders even though nothing is rendered, the visibility
+        // bounds read the meshes, and the text and image sizing reads the texture atlases
+        // otherwise added by the render and sprite plugins.
+        .init_asset::<Shader>()
+        .init_asset::<Mesh>()
+        .init_asset::<TextureAtlasLayout>()
+        .add_plugins((TextPlugin, UiPlugin))
+        .add_plugins(ScrollViewPlugin)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            FRAME,
+        )));
+        app.finish();
+        app.cleanup();
+
+        let window = app
+            .world_mut()
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(app.world());
+        app.world_mut().spawn(Camera2dBundle::default());
+        let root = app
+            .world_mut()
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Start,
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+
+        Self {
+            app,
+            window,
+            root,
+            touch_position: Vec2::ZERO,
+        }
+    }
+
+    /// Spawns a `ScrollView` of given `size` under `parent`, with `items` children
+    /// of `item_size` laid out along its axis.
+    pub fn spawn_view(
+        &mut self,
+        parent: Entity,
+        scroll_view: ScrollView,
+        size: Vec2,
+        items: usize,
+        item_size: f32,
+    ) -> TestView {
+        let horizontal = scroll_view.axis == ScrollAxis::Horizontal;
+        let world = self.app.world_mut();
+        let view = world
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Px(size.x),
+                        height: Val::Px(size.y),
+                        flex_shrink: 0.0,
+                        ..default()
+                    },
+                    ..default()
+                },
+                scroll_view,
+            ))
+            .set_parent(parent)
+            .id();
+        let content = world
+            .spawn((
+                NodeBundle {
+                    style: if horizontal {
+                        Style {
+                            flex_direction: FlexDirection::Row,
+                            height: Val::Percent(100.0),
+                            ..default()
+                        }
+                    } else {
+                        Style {
+                            flex_direction: FlexDirection::Column,
+                            width: Val::Percent(100.0),
+                            ..default()
+                        }
+                    },
+                    ..default()
+                },
+                ScrollableContent::default(),
+            ))
+            .set_parent(view)
+            .id();
+        self.spawn_items(content, items, item_size, horizontal);
+        TestView { view, content }
+    }
+
+    /// Adds `count` children of `size` along the scroll axis to the content.
+    pub fn spawn_items(&mut self, content: Entity, count: usize, size: f32, horizontal: bool) {
+        let world = self.app.world_mut();
+        for _ in 0..count {
+            let style = if horizontal {
+                Style {
+                    width: Val::Px(size),
+                    height: Val::Percent(100.0),
+                    flex_shrink: 0.0,
+                    ..default()
+                }
+            } else {
+                Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(size),
+                    flex_shrink: 0.0,
+                    ..default()
+                }
+            };
+            world
+                .spawn(NodeBundle { style, ..default() })
+                .set_parent(content);
+        }
+    }
+
+    /// Runs a single frame.
+    pub fn update(&mut self) {
+        self.app.update();
+    }
+
+    /// Runs the given number of frames.
+    pub fn run(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.app.update();
+        }
+    }
+
+    /// Runs enough frames for the scroll views to be set up and laid out.
+    pub fn settle(&mut self) {
+        self.run(3);
+    }
+
+    /// Current offset of the `ScrollableContent`.
+    pub fn offset(&self, content: Entity) -> Vec2 {
+        let scroll = self.app.world().get::<ScrollableContent>(content).unwrap();
+        Vec2::new(scroll.pos_x, scroll.pos_y)
+    }
+
+    /// Moves the content to the given offset, as a user system would.
+    pub fn set_offset(&mut self, content: Entity, offset: Vec2) {
+        let mut scroll = self
+            .app
+            .world_mut()
+            .get_mut::<ScrollableContent>(content)
+            .unwrap();
+        scroll.pos_x = offset.x;
+        scroll.pos_y = offset.y;
+    }
+
+    /// Center of the entity on the screen, in logical pixels.
+    pub fn center_of(&self, entity: Entity) -> Vec2 {
+        let world = self.app.world();
+        let node = world.get::<Node>(entity).unwrap();
+        let transform = world.get::<GlobalTransform>(entity).unwrap();
+        node.logical_rect(transform).center()
+    }
+
+    /// Moves the cursor over the given position and runs a frame, so the UI picks up
+    /// the hovered nodes.
+    pub fn hover(&mut self, position: Vec2) {
+        let mut window = self.app.world_mut().get_mut::<Window>(self.window).unwrap();
+        window.set_cursor_position(Some(position));
+        self.update();
+    }
+
+    /// Sends the mouse wheel event and runs a frame.
+    pub fn wheel(&mut self, unit: MouseScrollUnit, x: f32, y: f32) {
+        let window = self.window;
+        self.app
+            .world_mut()
+            .send_event(MouseWheel { unit, x, y, window });
+        self.update();
+    }
+
+    /// Scrolls the wheel by the given number of lines and runs a frame.
+    pub fn wheel_lines(&mut self, x: f32, y: f32) {
+        self.wheel(MouseScrollUnit::Line, x, y);
+    }
+
+    /// Scrolls the wheel by the given number of pixels and runs a frame.
+    pub fn wheel_pixels(&mut self, x: f32, y: f32) {
+        self.wheel(MouseScrollUnit::Pixel, x, y);
+    }
+
+    /// Presses the left mouse button at the current cursor position and runs a frame.
+    pub fn press(&mut self) {
+        self.mouse_button(ButtonState::Pressed);
+    }
+
+    /// Releases the left mouse button and runs a frame.
+    pub fn release(&mut self) {
+        self.mouse_button(ButtonState::Released);
+    }
+
+    fn mouse_button(&mut self, state: ButtonState) {
+        let window = self.window;
+        self.app.world_mut().send_event(MouseButtonInput {
+            button: MouseButton::Left,
+            state,
+            window,
+        });
+        self.update();
+    }
+
+    /// Moves the mouse by `delta` and runs a frame.
+    pub fn drag(&mut self, delta: Vec2) {
+        self.app.world_mut().send_event(MouseMotion { delta });
+        self.update();
+    }
+
+    /// Starts a touch at the given position and runs a frame.
+    pub fn touch_start(&mut self, position: Vec2) {
+        self.touch_position = position;
+        self.touch(TouchPhase::Started, position);
+    }
+
+    /// Moves the touch by `delta` and runs a frame.
+    pub fn touch_move(&mut self, delta: Vec2) {
+        self.touch_position += delta;
+        self.touch(TouchPhase::Moved, self.touch_position);
+    }
+
+    /// Ends the touch and runs a frame.
+    pub fn touch_end(&mut self) {
+        self.touch(TouchPhase::Ended, self.touch_position);
+    }
+
+    fn touch(&mut self, phase: TouchPhase, position: Vec2) {
+        self.fingers(&[(0, phase, position)]);
+    }
+
+    /// Sends the touch events of several fingers, given by their id, in the same frame
+    /// and runs it.
+    pub fn fingers(&mut self, touches: &[(u64, TouchPhase, Vec2)]) {
+        let window = self.window;
+        for &(id, phase, position) in touches {
+            self.app.world_mut().send_event(TouchInput {
+                phase,
+                position,
+                window,
+                force: None,
+                id,
+            });
+        }
+        self.update();
+    }
+
+    /// Presses and releases the key and runs a frame.
+    pub fn key(&mut self, key_code: KeyCode) {
+        self.send_key(key_code, ButtonState::Pressed);
+        self.send_key(key_code, ButtonState::Released);
+        self.update();
+    }
+
+    /// Presses the key without releasing it and runs a frame.
+    pub fn hold_key(&mut self, key_code: KeyCode) {
+        self.send_key(key_code, ButtonState::Pressed);
+        self.update();
+    }
+
+    /// Releases the held key and runs a frame.
+    pub fn release_key(&mut self, key_code: KeyCode) {
+        self.send_key(key_code, ButtonState::Released);
+        self.update();
+    }
+
+    fn send_key(&mut self, key_code: KeyCode, state: ButtonState) {
+        let window = self.window;
+        self.app.world_mut().send_event(KeyboardInput {
+            key_code,
+            logical_key: Key::Unidentified(NativeKey::Unidentified),
+            state,
+            window,
+        });
+    }
+
+    /// Sends the event and runs a frame.
+    pub fn send<E: Event>(&mut self, event: E) {
+        self.app.world_mut().send_event(event);
+        self.update();
+    }
+}
+
+/// Vertical view of 200x100 with 10 items of 50 pixels, so it scrolls by up to 400 pixels.
+pub fn vertical_view(harness: &mut Harness, scroll_view: ScrollView) -> TestView {
+    let root = harness.root;
+    harness.spawn_view(root, scroll_view, Vec2::new(200.0, 100.0), 10, 50.0)
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+/// Returns the `ScrollChanged` events sent during the last two frames.
+fn scroll_changed(h: &Harness) -> Vec<ScrollChanged> {
+    let events = h.app.world().resource::<Events<ScrollChanged>>();
+    events.get_reader().read(events).copied().collect()
+}
+
+#[test]
+fn scroll_changed_is_sent_for_input_and_manual_changes() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.hover(h.center_of(v.view));
+    h.update();
+    h.update();
+    assert!(scroll_changed(&h).is_empty());
+
+    h.wheel_lines(0.0, -5.0);
+    assert_eq!(
+        scroll_changed(&h),
+        vec![ScrollChanged {
+            entity: v.view,
+            pos: Vec2::new(0.0, -200.0),
+            progress: Vec2::new(0.0, 0.5),
+        }]
+    );
+
+    h.set_offset(v.content, Vec2::new(0.0, -400.0));
+    h.update();
+    assert_eq!(
+        scroll_changed(&h).last().unwrap().progress,
+        Vec2::new(0.0, 1.0)
+    );
+}
+
+#[test]
+fn scroll_changed_is_not_sent_while_idle() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.set_offset(v.content, Vec2::new(0.0, -100.0));
+    h.run(3);
+
+    h.app
+        .world_mut()
+        .get_mut::<ScrollableContent>(v.content)
+        .unwrap()
+        .set_changed();
+    h.run(2);
+    assert!(scroll_changed(&h).is_empty());
+}
+
+/// Returns the `ScrollBoundary` events sent during the last two frames.
+fn boundaries(h: &Harness) -> Vec<ScrollEdge> {
+    let events = h.app.world().resource::<Events<ScrollBoundary>>();
+    events.get_reader().read(events).map(|ev| ev.edge).collect()
+}
+
+#[test]
+fn scroll_boundary_is_sent_once_per_arrival() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.hover(h.center_of(v.view));
+    h.run(2);
+    assert!(boundaries(&h).is_empty());
+
+    h.wheel_lines(0.0, -100.0);
+    assert_eq!(boundaries(&h), vec![ScrollEdge::Bottom]);
+
+    // Pushing against the edge doesn't repeat the event.
+    h.run(2);
+    h.wheel_lines(0.0, -1.0);
+    assert!(boundaries(&h).is_empty());
+
+    h.wheel_lines(0.0, 1.0);
+    h.run(2);
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(boundaries(&h), vec![ScrollEdge::Bottom]);
+
+    h.wheel_lines(0.0, 100.0);
+    assert_eq!(boundaries(&h), vec![ScrollEdge::Bottom, ScrollEdge::Top]);
+}
+
+fn load_more_view() -> ScrollView {
+    ScrollView {
+        bounds: ScrollBounds {
+            end_threshold: Some(Val::Px(50.0)),
+            load_more_cooldown: 1.0,
+            ..default()
+        },
+        ..default()
+    }
+}
+
+#[test]
+fn scroll_reached_end_is_debounced_and_rearmed() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, load_more_view());
+    let mut reader = h
+        .app
+        .world()
+        .resource::<Events<ScrollReachedEnd>>()
+        .get_reader();
+    // The events only live for two frames, so they are read after every frame.
+    let mut run = |h: &mut Harness, frames: usize| {
+        let mut reached = 0;
+        for _ in 0..frames {
+            h.update();
+            let events = h.app.world().resource::<Events<ScrollReachedEnd>>();
+            reached += reader.read(events).count();
+        }
+        reached
+    };
+    h.settle();
+    assert_eq!(run(&mut h, 2), 0);
+
+    h.set_offset(v.content, Vec2::new(0.0, -360.0));
+    assert_eq!(run(&mut h, 1), 1);
+
+    // Staying near the end doesn't repeat the event until the cooldown passes.
+    assert_eq!(run(&mut h, 30), 0);
+    assert_eq!(run(&mut h, 40), 1);
+
+    // Leaving the end area re-arms it, so coming back sends it right away.
+    h.set_offset(v.content, Vec2::ZERO);
+    assert_eq!(run(&mut h, 1), 0);
+    h.set_offset(v.content, Vec2::new(0.0, -400.0));
+    assert_eq!(run(&mut h, 1), 1);
+}
+
+#[test]
+fn scroll_reached_end_is_not_sent_before_layout_or_for_fitting_content() {
+    let mut h = Harness::new();
+    let root = h.root;
+    let mut reader = h
+        .app
+        .world()
+        .resource::<Events<ScrollReachedEnd>>()
+        .get_reader();
+    vertical_view(&mut h, load_more_view());
+    h.spawn_view(root, load_more_view(), Vec2::new(200.0, 100.0), 1, 50.0);
+    h.run(10);
+
+    let events = h.app.world().resource::<Events<ScrollReachedEnd>>();
+    assert_eq!(reader.read(events).count(), 0);
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+/// Spawns a node of 20 pixels along the scroll axis under the frame.
+fn spawn_pinned(world: &mut World, frame: Entity, marker: impl Component) -> Entity {
+    world
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(20.0),
+                    ..default()
+                },
+                ..default()
+            },
+            marker,
+        ))
+        .set_parent(frame)
+        .id()
+}
+
+/// Frame of 200x140 with a 20 pixels high header and footer around a vertical view
+/// with 10 items of 50 pixels.
+fn framed_view(h: &mut Harness) -> (TestView, Entity) {
+    let root = h.root;
+    let world = h.app.world_mut();
+    let frame = world
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(200.0),
+                    height: Val::Px(140.0),
+                    ..default()
+                },
+                ..default()
+            },
+            ScrollFrame,
+        ))
+        .set_parent(root)
+        .id();
+    let header = spawn_pinned(world, frame, ScrollHeader);
+    let v = h.spawn_view(
+        frame,
+        ScrollView::default(),
+        Vec2::new(200.0, 300.0),
+        10,
+        50.0,
+    );
+    spawn_pinned(h.app.world_mut(), frame, ScrollFooter);
+    h.settle();
+    (v, header)
+}
+
+#[test]
+fn header_and_footer_are_excluded_from_the_viewport() {
+    let mut h = Harness::new();
+    let (v, _) = framed_view(&mut h);
+
+    let metrics = *h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.container_size, Vec2::new(200.0, 100.0));
+    assert_eq!(metrics.max_scroll, Vec2::new(0.0, 400.0));
+}
+
+#[test]
+fn header_stays_in_place_while_scrolling() {
+    let mut h = Harness::new();
+    let (v, header) = framed_view(&mut h);
+    let header_center = h.center_of(header);
+
+    h.hover(h.center_of(v.view));
+    h.wheel_lines(0.0, -3.0);
+    h.settle();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -120.0));
+    assert_eq!(h.center_of(header), header_center);
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use bevy::input::gamepad::{
+    GamepadAxisChangedEvent, GamepadButtonChangedEvent, GamepadConnection, GamepadConnectionEvent,
+    GamepadEvent, GamepadInfo,
+};
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+const PAD: Gamepad = Gamepad { id: 0 };
+
+/// Hovered vertical view scrolled with the gamepad, with the gamepad connected.
+fn gamepad_view(h: &mut Harness) -> TestView {
+    let v = vertical_view(
+        h,
+        ScrollView {
+            input: ScrollInput {
+                gamepad: true,
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+    h.send(GamepadEvent::Connection(GamepadConnectionEvent::new(
+        PAD,
+        GamepadConnection::Connected(GamepadInfo {
+            name: "Test".to_string(),
+        }),
+    )));
+    v
+}
+
+fn stick(h: &mut Harness, y: f32) {
+    h.send(GamepadEvent::Axis(GamepadAxisChangedEvent::new(
+        PAD,
+        GamepadAxisType::RightStickY,
+        y,
+    )));
+}
+
+fn dpad(h: &mut Harness, button_type: GamepadButtonType) {
+    for value in [1.0, 0.0] {
+        h.send(GamepadEvent::Button(GamepadButtonChangedEvent::new(
+            PAD,
+            button_type,
+            value,
+        )));
+    }
+}
+
+#[test]
+fn dpad_scrolls_by_scroll_speed() {
+    let mut h = Harness::new();
+    let v = gamepad_view(&mut h);
+
+    dpad(&mut h, GamepadButtonType::DPadDown);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -40.0));
+    dpad(&mut h, GamepadButtonType::DPadDown);
+    dpad(&mut h, GamepadButtonType::DPadUp);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -40.0));
+}
+
+#[test]
+fn stick_scrolls_with_the_deflection_over_time() {
+    let mut h = Harness::new();
+    let v = gamepad_view(&mut h);
+
+    stick(&mut h, -1.0);
+    h.run(5);
+    let expected = -800.0 * FRAME * 6.0;
+    assert!((h.offset(v.content).y - expected).abs() < 0.01);
+
+    stick(&mut h, 0.0);
+    let rest = h.offset(v.content);
+    stick(&mut h, -0.1);
+    h.run(10);
+    assert_eq!(h.offset(v.content), rest);
+}
+
+#[test]
+fn gamepad_is_ignored_unless_enabled() {
+    let mut h = Harness::new();
+    let v = gamepad_view(&mut h);
+    h.app
+        .world_mut()
+        .get_mut::<ScrollView>(v.view)
+        .unwrap()
+        .input
+        .gamepad = false;
+
+    dpad(&mut h, GamepadButtonType::DPadDown);
+    stick(&mut h, -1.0);
+    h.run(5);
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
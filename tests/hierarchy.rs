@@ -0,0 +1,210 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+/// Places an absolutely positioned overlay before the content of the view.
+fn add_overlay(h: &mut Harness, view: Entity) -> Entity {
+    let world = h.app.world_mut();
+    let overlay = world
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    world.entity_mut(view).insert_children(0, &[overlay]);
+    overlay
+}
+
+#[test]
+fn overlay_before_the_content_is_ignored() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    let overlay = add_overlay(&mut h, v.view);
+    h.settle();
+
+    let children = h.app.world().get::<Children>(v.view).unwrap();
+    assert_eq!(children[0], overlay);
+    let metrics = *h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.content_size, Vec2::new(200.0, 500.0));
+    assert_eq!(metrics.max_scroll, Vec2::new(0.0, 400.0));
+
+    h.hover(h.center_of(v.view));
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -40.0));
+    h.update();
+    let metrics = h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.offset, Vec2::new(0.0, -40.0));
+}
+
+#[test]
+fn scrollbar_moves_the_content_behind_an_overlay() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    add_overlay(&mut h, v.view);
+    h.app
+        .world_mut()
+        .entity_mut(v.view)
+        .insert(Scrollbar::default());
+    h.settle();
+
+    let thumb = h
+        .app
+        .world_mut()
+        .query::<(Entity, &ScrollbarThumb)>()
+        .iter(h.app.world())
+        .find(|(_, thumb)| thumb.view == v.view && !thumb.horizontal)
+        .map(|(entity, _)| entity)
+        .unwrap();
+    h.hover(h.center_of(thumb));
+    h.press();
+    h.drag(Vec2::new(0.0, 10.0));
+    h.release();
+    assert!(h.offset(v.content).y < 0.0);
+}
+
+#[test]
+fn spawned_scroll_view_has_the_content_laid_out_along_the_axis() {
+    use bevy::ecs::system::RunSystemOnce;
+
+    let mut h = Harness::new();
+    let root = h.root;
+    let (view, content) = h
+        .app
+        .world_mut()
+        .run_system_once(move |mut commands: Commands| {
+            let view = NodeBundle {
+                style: Style {
+                    width: Val::Px(200.0),
+                    height: Val::Px(100.0),
+                    ..default()
+                },
+                ..default()
+            };
+            let scroll_view = ScrollView {
+                axis: ScrollAxis::Horizontal,
+                ..default()
+            };
+            let (view, content) = commands.spawn_scroll_view(view, scroll_view, |content| {
+                for _ in 0..10 {
+                    content.spawn(NodeBundle {
+                        style: Style {
+                            width: Val::Px(50.0),
+                            flex_shrink: 0.0,
+                            ..default()
+                        },
+                        ..default()
+                    });
+                }
+            });
+            commands.entity(root).add_child(view);
+            (view, content)
+        });
+    h.settle();
+
+    assert_eq!(h.app.world().get::<Parent>(content).unwrap().get(), view);
+    let metrics = *h.app.world().get::<ScrollMetrics>(view).unwrap();
+    assert_eq!(metrics.content_size, Vec2::new(500.0, 100.0));
+    assert_eq!(metrics.max_scroll, Vec2::new(300.0, 0.0));
+}
+
+#[test]
+fn bundles_set_up_a_vertical_view() {
+    let mut h = Harness::new();
+    let root = h.root;
+    let world = h.app.world_mut();
+    let mut view = ScrollViewBundle::default();
+    view.node.style.width = Val::Px(200.0);
+    view.node.style.height = Val::Px(100.0);
+    let view = world.spawn(view).set_parent(root).id();
+    let content = world
+        .spawn(ScrollableContentBundle::default())
+        .set_parent(view)
+        .with_children(|p| {
+            for _ in 0..10 {
+                p.spawn(NodeBundle {
+                    style: Style {
+                        height: Val::Px(50.0),
+                        ..default()
+                    },
+                    ..default()
+                });
+            }
+        })
+        .id();
+    h.settle();
+
+    let style = h.app.world().get::<Style>(view).unwrap();
+    assert_eq!(style.overflow, Overflow::clip());
+    let metrics = *h.app.world().get::<ScrollMetrics>(view).unwrap();
+    assert_eq!(metrics.content_size, Vec2::new(200.0, 500.0));
+    assert_eq!(metrics.max_scroll, Vec2::new(0.0, 400.0));
+
+    h.hover(h.center_of(view));
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(content), Vec2::new(0.0, -40.0));
+}
+
+#[test]
+fn only_the_first_content_is_scrolled_by_the_input() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    let stray = h
+        .app
+        .world_mut()
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    height: Val::Px(500.0),
+                    ..default()
+                },
+                ..default()
+            },
+            ScrollableContent::default(),
+        ))
+        .set_parent(v.view)
+        .id();
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -40.0));
+    assert_eq!(h.offset(stray), Vec2::ZERO);
+}
+
+#[test]
+fn unmanaged_style_keeps_the_user_alignment() {
+    let mut h = Harness::new();
+    let root = h.root;
+    let view = h
+        .app
+        .world_mut()
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    align_items: AlignItems::Center,
+                    flex_direction: FlexDirection::RowReverse,
+                    ..default()
+                },
+                ..default()
+            },
+            ScrollView {
+                manage_style: false,
+                ..default()
+            },
+        ))
+        .set_parent(root)
+        .id();
+    h.update();
+
+    let style = h.app.world().get::<Style>(view).unwrap();
+    assert_eq!(style.align_items, AlignItems::Center);
+    assert_eq!(style.flex_direction, FlexDirection::RowReverse);
+    assert_eq!(style.overflow, Overflow::clip());
+}
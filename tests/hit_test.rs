@@ -0,0 +1,38 @@
+mod common;
+
+use bevy::{ecs::system::SystemState, prelude::*};
+use bevy_simple_scroll_view::*;
+use common::*;
+
+fn hit_test(h: &mut Harness, point: Vec2) -> Option<(Entity, Vec2)> {
+    let mut state = SystemState::<ScrollViewHitTest>::new(h.app.world_mut());
+    state.get(h.app.world()).hit_test(point)
+}
+
+#[test]
+fn hit_test_returns_the_innermost_view() {
+    let mut h = Harness::new();
+    let root = h.root;
+    let outer = h.spawn_view(root, ScrollView::default(), Vec2::new(300.0, 200.0), 0, 0.0);
+    let inner = h.spawn_view(
+        outer.content,
+        ScrollView::default(),
+        Vec2::new(200.0, 100.0),
+        10,
+        50.0,
+    );
+    h.spawn_items(outer.content, 10, 50.0, false);
+    h.settle();
+    h.set_offset(inner.content, Vec2::new(0.0, -30.0));
+    h.settle();
+
+    assert_eq!(
+        hit_test(&mut h, Vec2::new(50.0, 40.0)),
+        Some((inner.view, Vec2::new(50.0, 70.0)))
+    );
+    assert_eq!(
+        hit_test(&mut h, Vec2::new(250.0, 40.0)),
+        Some((outer.view, Vec2::new(250.0, 40.0)))
+    );
+    assert_eq!(hit_test(&mut h, Vec2::new(500.0, 40.0)), None);
+}
@@ -0,0 +1,1003 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+#[test]
+fn wheel_line_scrolls_by_scroll_speed() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::from(40.0));
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -40.0));
+    h.wheel_lines(0.0, -2.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -120.0));
+}
+
+#[test]
+fn wheel_line_distance_does_not_depend_on_the_frame_time() {
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    let scrolled = |frame_time: f32| {
+        let mut h = Harness::new();
+        h.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                frame_time,
+            )));
+        let v = vertical_view(&mut h, ScrollView::from(25.0));
+        h.settle();
+        h.hover(h.center_of(v.view));
+
+        h.wheel_lines(0.0, -3.0);
+        let lines = h.offset(v.content);
+        h.wheel_pixels(0.0, -10.0);
+        (lines, h.offset(v.content) - lines)
+    };
+
+    // Each line moves the content by `scroll_speed` pixels, without any delta time factor.
+    let slow = scrolled(1.0 / 30.0);
+    assert_eq!(slow, (Vec2::new(0.0, -75.0), Vec2::new(0.0, -10.0)));
+    assert_eq!(scrolled(1.0 / 144.0), slow);
+}
+
+#[test]
+fn wheel_pixels_are_applied_one_to_one() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::from(40.0));
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_pixels(0.0, -15.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -15.0));
+}
+
+#[test]
+fn pixel_scroll_speed_scales_only_the_pixel_input() {
+    let mut h = Harness::new();
+    let v = vertical_view(
+        &mut h,
+        ScrollView {
+            input: ScrollInput {
+                pixel_scroll_speed: 2.0,
+                ..default()
+            },
+            ..ScrollView::from(40.0)
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_pixels(0.0, -15.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -30.0));
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -70.0));
+}
+
+#[test]
+fn physical_pixels_are_scaled_to_the_ui() {
+    let mut h = Harness::new();
+    let window = h.window;
+    h.app
+        .world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .resolution
+        .set_scale_factor_override(Some(2.0));
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_pixels(0.0, -20.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -10.0));
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -50.0));
+    h.press();
+    h.drag(Vec2::new(0.0, -20.0));
+    h.release();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -60.0));
+}
+
+#[test]
+fn max_wheel_step_limits_huge_wheel_deltas() {
+    let mut h = Harness::new();
+    let v = vertical_view(
+        &mut h,
+        ScrollView {
+            input: ScrollInput {
+                max_wheel_step: Some(60.0),
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_lines(0.0, -50.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -60.0));
+    h.wheel_pixels(0.0, 5000.0);
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+    h.wheel_pixels(0.0, -20.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -20.0));
+}
+
+#[test]
+fn wheel_is_clamped_to_the_content() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_lines(0.0, 1.0);
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+    h.wheel_lines(0.0, -100.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+}
+
+#[test]
+fn non_finite_wheel_deltas_are_ignored() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.hover(h.center_of(v.view));
+    h.wheel_lines(0.0, -1.0);
+
+    h.wheel_pixels(0.0, f32::NAN);
+    let offset = h.offset(v.content);
+    assert!(offset.is_finite());
+    assert_eq!(offset, Vec2::new(0.0, -40.0));
+
+    h.app
+        .world_mut()
+        .get_mut::<ScrollView>(v.view)
+        .unwrap()
+        .scroll_speed = f32::INFINITY;
+    h.wheel_lines(0.0, -1.0);
+    let offset = h.offset(v.content);
+    assert!(offset.is_finite());
+    assert_eq!(offset, Vec2::new(0.0, -40.0));
+}
+
+#[test]
+fn wheel_outside_of_the_view_is_ignored() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.hover(Vec2::new(700.0, 500.0));
+
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
+
+#[test]
+fn mouse_drag_moves_content_by_drag_speed() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    let fast = h.spawn_view(
+        h.root,
+        ScrollView {
+            input: ScrollInput {
+                drag_speed: 2.0,
+                ..default()
+            },
+            ..default()
+        },
+        Vec2::new(200.0, 100.0),
+        10,
+        50.0,
+    );
+    h.settle();
+
+    h.hover(h.center_of(v.view));
+    h.press();
+    h.drag(Vec2::new(0.0, -30.0));
+    h.release();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -30.0));
+
+    h.hover(h.center_of(fast.view));
+    h.press();
+    h.drag(Vec2::new(0.0, -30.0));
+    h.release();
+    assert_eq!(h.offset(fast.content), Vec2::new(0.0, -60.0));
+}
+
+#[test]
+fn touch_scrolls_only_past_the_threshold() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.touch_start(h.center_of(v.view));
+    h.touch_move(Vec2::new(0.0, -4.0));
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+    h.touch_move(Vec2::new(0.0, -16.0));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -16.0));
+    h.touch_end();
+}
+
+/// Vertical view with a button of 50 pixels at the start of its content.
+fn view_with_button(h: &mut Harness) -> (TestView, Entity) {
+    let v = vertical_view(h, ScrollView::default());
+    let button = h
+        .app
+        .world_mut()
+        .spawn(ButtonBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Px(50.0),
+                flex_shrink: 0.0,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    h.app
+        .world_mut()
+        .entity_mut(v.content)
+        .insert_children(0, &[button]);
+    h.settle();
+    (v, button)
+}
+
+#[test]
+fn short_tap_presses_a_button_in_the_content() {
+    let mut h = Harness::new();
+    let (v, button) = view_with_button(&mut h);
+    let interaction = |h: &Harness| *h.app.world().get::<Interaction>(button).unwrap();
+
+    h.touch_start(h.center_of(button));
+    assert_eq!(interaction(&h), Interaction::Pressed);
+    // A finger wobbling below the threshold keeps the tap on the button.
+    h.touch_move(Vec2::new(0.0, -4.0));
+    h.touch_move(Vec2::new(0.0, -3.0));
+    assert_eq!(interaction(&h), Interaction::Pressed);
+    h.touch_end();
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
+
+#[test]
+fn touch_starting_on_a_button_scrolls_past_the_threshold() {
+    let mut h = Harness::new();
+    let (v, button) = view_with_button(&mut h);
+
+    h.touch_start(h.center_of(button));
+    h.touch_move(Vec2::new(0.0, -4.0));
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+    h.touch_move(Vec2::new(0.0, -16.0));
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -16.0));
+    h.touch_end();
+}
+
+#[test]
+fn second_finger_does_not_multiply_the_touch_scroll() {
+    use bevy::input::touch::TouchPhase;
+
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    let first = h.center_of(v.view);
+    let second = first + Vec2::new(20.0, 0.0);
+
+    h.fingers(&[(0, TouchPhase::Started, first)]);
+    h.fingers(&[(1, TouchPhase::Started, second)]);
+    let up = Vec2::new(0.0, -30.0);
+    h.fingers(&[
+        (0, TouchPhase::Moved, first + up),
+        (1, TouchPhase::Moved, second + up),
+    ]);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -30.0));
+
+    // Lifting the first finger stops the scrolling, it doesn't jump to the second one.
+    h.fingers(&[(0, TouchPhase::Ended, first + up)]);
+    h.fingers(&[(1, TouchPhase::Moved, second + up * 2.0)]);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -30.0));
+    h.fingers(&[(1, TouchPhase::Ended, second + up * 2.0)]);
+}
+
+/// Outer view of 300x200 containing the inner view of 200x100 with `inner_items` of 50 pixels,
+/// followed by 10 items of 50 pixels.
+fn nested_views_with(
+    h: &mut Harness,
+    propagate: impl Into<ScrollPropagation>,
+    inner_items: usize,
+) -> (TestView, TestView) {
+    let root = h.root;
+    let outer = h.spawn_view(root, ScrollView::default(), Vec2::new(300.0, 200.0), 0, 0.0);
+    let inner = h.spawn_view(
+        outer.content,
+        ScrollView {
+            propagate: propagate.into(),
+            ..default()
+        },
+        Vec2::new(200.0, 100.0),
+        inner_items,
+        50.0,
+    );
+    h.spawn_items(outer.content, 10, 50.0, false);
+    h.settle();
+    (outer, inner)
+}
+
+/// Nested views where the inner one scrolls by up to 50 pixels.
+fn nested_views(h: &mut Harness, propagate: impl Into<ScrollPropagation>) -> (TestView, TestView) {
+    nested_views_with(h, propagate, 3)
+}
+
+/// Returns the `consumed` flag of the last `ScrollConsumed` event sent for the view.
+fn last_consumed(h: &Harness, view: Entity) -> Option<bool> {
+    let events = h.app.world().resource::<Events<ScrollConsumed>>();
+    events
+        .get_reader()
+        .read(events)
+        .filter(|ev| ev.entity == view)
+        .last()
+        .map(|ev| ev.consumed)
+}
+
+#[test]
+fn inverted_drag_moves_the_content_against_the_pointer() {
+    let mut h = Harness::new();
+    let v = vertical_view(
+        &mut h,
+        ScrollView {
+            input: ScrollInput {
+                invert_drag: true,
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.press();
+    h.drag(Vec2::new(0.0, 30.0));
+    h.release();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -30.0));
+
+    // The wheel keeps its direction.
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -70.0));
+}
+
+#[test]
+fn nested_wheel_passes_the_remaining_delta_to_the_parent() {
+    let mut h = Harness::new();
+    let (outer, inner) = nested_views(&mut h, true);
+    h.hover(h.center_of(inner.view));
+
+    h.wheel_lines(0.0, -2.0);
+    assert_eq!(h.offset(inner.content), Vec2::new(0.0, -50.0));
+    assert_eq!(h.offset(outer.content), Vec2::new(0.0, -30.0));
+}
+
+#[test]
+fn wheel_events_of_a_frame_are_summed_before_scrolling() {
+    use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+
+    let mut h = Harness::new();
+    let (outer, inner) = nested_views(&mut h, true);
+    h.hover(h.center_of(inner.view));
+    let mut consumed = h
+        .app
+        .world()
+        .resource::<Events<ScrollConsumed>>()
+        .get_reader();
+
+    let window = h.window;
+    for (unit, y) in [
+        (MouseScrollUnit::Line, -0.5),
+        (MouseScrollUnit::Line, -0.5),
+        (MouseScrollUnit::Pixel, -20.0),
+    ] {
+        h.app.world_mut().send_event(MouseWheel {
+            unit,
+            x: 0.0,
+            y,
+            window,
+        });
+    }
+    h.update();
+
+    // A line and 20 pixels, 50 of which fit into the inner view.
+    assert_eq!(h.offset(inner.content), Vec2::new(0.0, -50.0));
+    assert_eq!(h.offset(outer.content), Vec2::new(0.0, -10.0));
+    let events = h.app.world().resource::<Events<ScrollConsumed>>();
+    let consumed: Vec<_> = consumed
+        .read(events)
+        .map(|ev| (ev.entity, ev.consumed))
+        .collect();
+    assert_eq!(consumed, vec![(inner.view, false), (outer.view, true)]);
+}
+
+#[test]
+fn nested_drag_passes_the_remaining_delta_to_the_parent() {
+    let mut h = Harness::new();
+    let (outer, inner) = nested_views(&mut h, true);
+    h.hover(h.center_of(inner.view));
+
+    h.press();
+    h.drag(Vec2::new(0.0, -80.0));
+    h.release();
+    assert_eq!(h.offset(inner.content), Vec2::new(0.0, -50.0));
+    assert_eq!(h.offset(outer.content), Vec2::new(0.0, -30.0));
+}
+
+#[test]
+fn nested_view_without_propagation_consumes_the_input() {
+    let mut h = Harness::new();
+    let (outer, inner) = nested_views(&mut h, false);
+    h.hover(h.center_of(inner.view));
+
+    h.wheel_lines(0.0, -2.0);
+    h.press();
+    h.drag(Vec2::new(0.0, -80.0));
+    h.release();
+    assert_eq!(h.offset(inner.content), Vec2::new(0.0, -50.0));
+    assert_eq!(h.offset(outer.content), Vec2::ZERO);
+}
+
+#[test]
+fn nested_view_within_its_range_consumes_the_input() {
+    for propagate in [false, true] {
+        let mut h = Harness::new();
+        let (outer, inner) = nested_views(&mut h, propagate);
+        h.hover(h.center_of(inner.view));
+
+        h.wheel_pixels(0.0, -20.0);
+        assert_eq!(h.offset(inner.content), Vec2::new(0.0, -20.0));
+        assert_eq!(h.offset(outer.content), Vec2::ZERO);
+        assert_eq!(last_consumed(&h, inner.view), Some(true));
+    }
+}
+
+#[test]
+fn nested_view_at_its_end_passes_the_input_only_with_propagation() {
+    let mut h = Harness::new();
+    let (_, inner) = nested_views(&mut h, true);
+    h.hover(h.center_of(inner.view));
+    h.wheel_lines(0.0, -2.0);
+    assert_eq!(last_consumed(&h, inner.view), Some(false));
+
+    let mut h = Harness::new();
+    let (_, inner) = nested_views(&mut h, false);
+    h.hover(h.center_of(inner.view));
+    h.wheel_lines(0.0, -2.0);
+    assert_eq!(last_consumed(&h, inner.view), Some(true));
+}
+
+#[test]
+fn nested_view_always_propagating_scrolls_together_with_the_parent() {
+    let mut h = Harness::new();
+    let (outer, inner) = nested_views(&mut h, ScrollPropagation::Always);
+    h.hover(h.center_of(inner.view));
+
+    h.wheel_pixels(0.0, -20.0);
+    assert_eq!(h.offset(inner.content), Vec2::new(0.0, -20.0));
+    assert_eq!(h.offset(outer.content), Vec2::new(0.0, -20.0));
+    assert_eq!(last_consumed(&h, inner.view), Some(false));
+}
+
+#[test]
+fn nested_view_with_fitting_content_passes_the_input() {
+    for propagate in [false, true] {
+        let mut h = Harness::new();
+        let (outer, inner) = nested_views_with(&mut h, propagate, 2);
+        h.hover(h.center_of(inner.view));
+
+        h.wheel_lines(0.0, -1.0);
+        assert_eq!(h.offset(inner.content), Vec2::ZERO);
+        assert_eq!(h.offset(outer.content), Vec2::new(0.0, -40.0));
+    }
+}
+
+#[test]
+fn wheel_over_a_button_in_the_content_scrolls_the_view() {
+    let mut h = Harness::new();
+    let (outer, inner) = nested_views(&mut h, false);
+    let button = h
+        .app
+        .world_mut()
+        .spawn(ButtonBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Px(50.0),
+                flex_shrink: 0.0,
+                ..default()
+            },
+            ..default()
+        })
+        .id();
+    h.app
+        .world_mut()
+        .entity_mut(inner.content)
+        .insert_children(0, &[button]);
+    h.settle();
+    h.hover(h.center_of(button));
+    assert_eq!(
+        *h.app.world().get::<Interaction>(button).unwrap(),
+        Interaction::Hovered
+    );
+
+    h.wheel_pixels(0.0, -20.0);
+    assert_eq!(h.offset(inner.content), Vec2::new(0.0, -20.0));
+    assert_eq!(h.offset(outer.content), Vec2::ZERO);
+}
+
+#[test]
+fn disabled_view_ignores_the_input_and_passes_it_to_the_parent() {
+    let mut h = Harness::new();
+    let (outer, inner) = nested_views(&mut h, false);
+    h.app
+        .world_mut()
+        .get_mut::<ScrollView>(inner.view)
+        .unwrap()
+        .enabled = false;
+    h.hover(h.center_of(inner.view));
+
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(inner.content), Vec2::ZERO);
+    assert_eq!(h.offset(outer.content), Vec2::new(0.0, -40.0));
+    assert_eq!(last_consumed(&h, inner.view), None);
+
+    h.app
+        .world_mut()
+        .get_mut::<ScrollView>(inner.view)
+        .unwrap()
+        .enabled = true;
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(inner.content), Vec2::new(0.0, -40.0));
+    assert_eq!(h.offset(outer.content), Vec2::new(0.0, -40.0));
+}
+
+#[test]
+fn fitting_content_consumes_the_input_when_configured() {
+    let mut h = Harness::new();
+    let (outer, inner) = nested_views_with(&mut h, false, 2);
+    h.app
+        .world_mut()
+        .get_mut::<ScrollView>(inner.view)
+        .unwrap()
+        .input
+        .consume_when_fitting = true;
+    h.hover(h.center_of(inner.view));
+
+    h.wheel_lines(0.0, -1.0);
+    h.press();
+    h.drag(Vec2::new(0.0, -30.0));
+    h.release();
+    assert_eq!(h.offset(inner.content), Vec2::ZERO);
+    assert_eq!(h.offset(outer.content), Vec2::ZERO);
+    assert_eq!(last_consumed(&h, inner.view), Some(true));
+}
+
+fn horizontal_view(h: &mut Harness, input: ScrollInput) -> TestView {
+    let root = h.root;
+    let v = h.spawn_view(
+        root,
+        ScrollView {
+            axis: ScrollAxis::Horizontal,
+            input,
+            ..default()
+        },
+        Vec2::new(200.0, 100.0),
+        10,
+        50.0,
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+    v
+}
+
+#[test]
+fn constructors_set_the_axis_and_speed() {
+    let mut h = Harness::new();
+    let root = h.root;
+    let v = h.spawn_view(
+        root,
+        ScrollView::horizontal(25.0).with_propagate(true),
+        Vec2::new(200.0, 100.0),
+        10,
+        50.0,
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_lines(-1.0, 0.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-25.0, 0.0));
+    let scroll_view = h.app.world().get::<ScrollView>(v.view).unwrap();
+    assert_eq!(scroll_view.propagate, ScrollPropagation::AtBoundary);
+    assert_eq!(
+        ScrollView::both(10.0).with_speed(30.0).axis,
+        ScrollAxis::Both
+    );
+    assert_eq!(ScrollView::vertical(30.0).scroll_speed, 30.0);
+}
+
+#[test]
+fn horizontal_view_scrolls_with_the_vertical_wheel_by_default() {
+    let mut h = Harness::new();
+    let v = horizontal_view(&mut h, ScrollInput::default());
+
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-40.0, 0.0));
+}
+
+#[test]
+fn horizontal_view_ignores_the_vertical_wheel_when_disabled() {
+    let mut h = Harness::new();
+    let v = horizontal_view(
+        &mut h,
+        ScrollInput {
+            wheel_y_scrolls_horizontal: false,
+            ..default()
+        },
+    );
+
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+    h.wheel_lines(-1.0, 0.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-40.0, 0.0));
+}
+
+#[test]
+fn horizontal_wheel_scrolls_the_horizontal_view() {
+    let mut h = Harness::new();
+    let v = horizontal_view(&mut h, ScrollInput::default());
+
+    h.wheel_lines(-1.0, 0.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-40.0, 0.0));
+    h.wheel_pixels(-15.0, 0.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-55.0, 0.0));
+}
+
+#[test]
+fn horizontal_scroll_speed_overrides_the_horizontal_axis() {
+    let mut h = Harness::new();
+    let v = horizontal_view(
+        &mut h,
+        ScrollInput {
+            horizontal_scroll_speed: Some(10.0),
+            ..default()
+        },
+    );
+
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-10.0, 0.0));
+    h.wheel_lines(-2.0, 0.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-30.0, 0.0));
+
+    let mut h = Harness::new();
+    let v = panning_view(&mut h);
+    h.app
+        .world_mut()
+        .get_mut::<ScrollView>(v.view)
+        .unwrap()
+        .input
+        .horizontal_scroll_speed = Some(-10.0);
+    h.wheel_lines(-1.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -40.0));
+    h.wheel_lines(1.0, 0.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-10.0, -40.0));
+}
+
+#[test]
+fn line_step_sets_the_wheel_distance_independently_of_the_keyboard() {
+    let mut h = Harness::new();
+    let v = vertical_view(
+        &mut h,
+        ScrollView {
+            input: ScrollInput {
+                line_step: Some(15.0),
+                keyboard: true,
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_lines(0.0, -2.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -30.0));
+    h.key(KeyCode::ArrowDown);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -70.0));
+    h.wheel_pixels(0.0, -10.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -80.0));
+}
+
+#[test]
+fn adaptive_speed_scales_the_line_with_the_content_length() {
+    let mut h = Harness::new();
+    let v = vertical_view(
+        &mut h,
+        ScrollView {
+            input: ScrollInput {
+                adaptive_speed: true,
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    // The content is five times longer than the view.
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -200.0));
+    h.wheel_pixels(0.0, -10.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -210.0));
+}
+
+#[test]
+fn horizontal_wheel_takes_precedence_over_the_vertical_one() {
+    let mut h = Harness::new();
+    let v = horizontal_view(&mut h, ScrollInput::default());
+
+    h.wheel_lines(-1.0, -3.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-40.0, 0.0));
+}
+
+#[test]
+fn diagonal_drag_does_not_move_the_axis_without_overflow() {
+    let mut h = Harness::new();
+    let v = vertical_view(
+        &mut h,
+        ScrollView {
+            axis: ScrollAxis::Both,
+            bounds: ScrollBounds {
+                boundary_mode: BoundaryMode::Free,
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.press();
+    h.drag(Vec2::new(-20.0, -30.0));
+    h.release();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -30.0));
+
+    h.touch_start(h.center_of(v.view));
+    h.touch_move(Vec2::new(-20.0, -30.0));
+    h.touch_end();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -60.0));
+}
+
+/// View scrolling on both axes with content of 400x500 in a 200x100 view.
+fn panning_view(h: &mut Harness) -> TestView {
+    let v = vertical_view(
+        h,
+        ScrollView {
+            axis: ScrollAxis::Both,
+            ..default()
+        },
+    );
+    h.app
+        .world_mut()
+        .get_mut::<Style>(v.content)
+        .unwrap()
+        .min_width = Val::Px(400.0);
+    h.settle();
+    h.hover(h.center_of(v.view));
+    v
+}
+
+#[test]
+fn shift_wheel_scrolls_horizontally() {
+    let mut h = Harness::new();
+    let v = panning_view(&mut h);
+
+    h.hold_key(KeyCode::ShiftLeft);
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-40.0, 0.0));
+    h.release_key(KeyCode::ShiftLeft);
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-40.0, -40.0));
+
+    h.app
+        .world_mut()
+        .get_mut::<ScrollView>(v.view)
+        .unwrap()
+        .input
+        .shift_wheel_horizontal = false;
+    h.hold_key(KeyCode::ShiftRight);
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-40.0, -80.0));
+}
+
+#[test]
+fn both_axes_pan_diagonally() {
+    let mut h = Harness::new();
+    let v = panning_view(&mut h);
+
+    h.wheel_pixels(-30.0, -20.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-30.0, -20.0));
+
+    h.press();
+    h.drag(Vec2::new(-10.0, -10.0));
+    h.release();
+    assert_eq!(h.offset(v.content), Vec2::new(-40.0, -30.0));
+
+    h.wheel_pixels(-1000.0, -1000.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-200.0, -400.0));
+}
+
+#[test]
+fn smooth_wheel_eases_towards_the_clamped_target() {
+    let mut h = Harness::new();
+    let v = vertical_view(
+        &mut h,
+        ScrollView {
+            animation: ScrollAnimation {
+                smooth_time: 0.1,
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_lines(0.0, -20.0);
+    let first_frame = h.offset(v.content).y;
+    assert!(first_frame > -400.0);
+    for _ in 0..60 {
+        h.update();
+        assert!(h.offset(v.content).y >= -400.0);
+    }
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+    let content = h.app.world().entity(v.content);
+    assert!(!content.contains::<SmoothScroll>());
+}
+
+fn smooth_view(h: &mut Harness) -> TestView {
+    vertical_view(
+        h,
+        ScrollView {
+            animation: ScrollAnimation {
+                smooth_time: 0.1,
+                ..default()
+            },
+            ..default()
+        },
+    )
+}
+
+fn smooth_target(h: &Harness, content: Entity) -> Option<Vec2> {
+    h.app
+        .world()
+        .get::<SmoothScroll>(content)
+        .map(|smooth| smooth.target)
+}
+
+#[test]
+fn smooth_wheel_during_the_animation_extends_the_target() {
+    let mut h = Harness::new();
+    let v = smooth_view(&mut h);
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_lines(0.0, -2.0);
+    h.update();
+    assert_eq!(smooth_target(&h, v.content), Some(Vec2::new(0.0, -80.0)));
+    let before = h.offset(v.content).y;
+    assert!(before < 0.0 && before > -80.0);
+
+    h.wheel_lines(0.0, -2.0);
+    assert_eq!(smooth_target(&h, v.content), Some(Vec2::new(0.0, -160.0)));
+    // The content keeps easing from where it was instead of jumping.
+    let after = h.offset(v.content).y;
+    assert!(after < before && after > -160.0);
+
+    h.run(60);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -160.0));
+}
+
+#[test]
+fn shrinking_content_during_the_animation_clamps_the_target() {
+    let mut h = Harness::new();
+    let v = smooth_view(&mut h);
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_lines(0.0, -20.0);
+    h.run(4);
+    assert!(h.offset(v.content).y < -50.0);
+
+    let children: Vec<Entity> = h
+        .app
+        .world()
+        .get::<Children>(v.content)
+        .unwrap()
+        .iter()
+        .copied()
+        .collect();
+    for child in &children[3..] {
+        h.app.world_mut().entity_mut(*child).despawn_recursive();
+    }
+    // The layout picks up the new size at the end of the first frame.
+    h.run(2);
+    assert!(h.offset(v.content).y >= -50.0);
+    if let Some(target) = smooth_target(&h, v.content) {
+        assert_eq!(target, Vec2::new(0.0, -50.0));
+    }
+
+    h.run(60);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -50.0));
+    assert!(smooth_target(&h, v.content).is_none());
+}
+
+#[test]
+fn smooth_wheel_does_not_overshoot_the_target() {
+    let mut h = Harness::new();
+    let v = smooth_view(&mut h);
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.wheel_lines(0.0, -5.0);
+    let mut previous = h.offset(v.content).y;
+    for _ in 0..60 {
+        h.update();
+        let y = h.offset(v.content).y;
+        assert!(y <= previous && y >= -200.0);
+        previous = y;
+    }
+    assert_eq!(previous, -200.0);
+}
+
+#[test]
+fn hidden_views_ignore_the_input() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    let root = h.root;
+    *h.app.world_mut().get_mut::<Visibility>(root).unwrap() = Visibility::Hidden;
+    h.update();
+    h.wheel_lines(0.0, -1.0);
+    h.press();
+    h.drag(Vec2::new(0.0, -20.0));
+    h.release();
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+
+    *h.app.world_mut().get_mut::<Visibility>(root).unwrap() = Visibility::Inherited;
+    h.update();
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -40.0));
+}
+
+#[test]
+fn drag_takes_precedence_over_the_wheel_in_the_same_frame() {
+    use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
+
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.hover(h.center_of(v.view));
+    h.press();
+
+    let window = h.window;
+    h.app.world_mut().send_event(MouseMotion {
+        delta: Vec2::new(0.0, -20.0),
+    });
+    h.app.world_mut().send_event(MouseWheel {
+        unit: MouseScrollUnit::Line,
+        x: 0.0,
+        y: -1.0,
+        window,
+    });
+    h.update();
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -20.0));
+
+    h.release();
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -60.0));
+}
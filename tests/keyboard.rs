@@ -0,0 +1,155 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+fn active_view(h: &Harness) -> Option<Entity> {
+    h.app.world().resource::<ActiveScrollView>().0
+}
+
+fn keyboard_view() -> ScrollView {
+    ScrollView {
+        input: ScrollInput {
+            keyboard: true,
+            ..default()
+        },
+        ..default()
+    }
+}
+
+fn click(h: &mut Harness, position: Vec2) {
+    h.hover(position);
+    h.press();
+    h.release();
+}
+
+#[test]
+fn keyboard_scrolls_the_clicked_view_after_the_cursor_leaves() {
+    let mut h = Harness::new();
+    let first = vertical_view(&mut h, keyboard_view());
+    let second = vertical_view(&mut h, keyboard_view());
+    h.settle();
+
+    let point = h.center_of(first.view);
+    click(&mut h, point);
+    assert_eq!(active_view(&h), Some(first.view));
+    h.hover(h.center_of(second.view));
+
+    h.key(KeyCode::PageDown);
+    assert_eq!(h.offset(first.content), Vec2::new(0.0, -90.0));
+    h.key(KeyCode::ArrowDown);
+    assert_eq!(h.offset(first.content), Vec2::new(0.0, -130.0));
+    h.key(KeyCode::End);
+    assert_eq!(h.offset(first.content), Vec2::new(0.0, -400.0));
+    h.key(KeyCode::Home);
+    assert_eq!(h.offset(first.content), Vec2::ZERO);
+    assert_eq!(h.offset(second.content), Vec2::ZERO);
+}
+
+#[test]
+fn keyboard_follows_the_focus() {
+    let mut h = Harness::new();
+    let first = vertical_view(&mut h, keyboard_view());
+    let second = vertical_view(&mut h, keyboard_view());
+    h.settle();
+
+    let point = h.center_of(first.view);
+    click(&mut h, point);
+    let point = h.center_of(second.view);
+    click(&mut h, point);
+    assert_eq!(active_view(&h), Some(second.view));
+    h.key(KeyCode::PageDown);
+    assert_eq!(h.offset(first.content), Vec2::ZERO);
+    assert_eq!(h.offset(second.content), Vec2::new(0.0, -90.0));
+
+    h.app.world_mut().resource_mut::<ActiveScrollView>().0 = Some(first.view);
+    h.key(KeyCode::PageDown);
+    assert_eq!(h.offset(first.content), Vec2::new(0.0, -90.0));
+}
+
+#[test]
+fn clicking_outside_clears_the_active_view() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, keyboard_view());
+    h.settle();
+
+    let point = h.center_of(v.view);
+    click(&mut h, point);
+    click(&mut h, Vec2::new(700.0, 500.0));
+    assert_eq!(active_view(&h), None);
+    h.key(KeyCode::PageDown);
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
+
+#[test]
+fn clicking_a_nested_view_activates_the_inner_one() {
+    let mut h = Harness::new();
+    let root = h.root;
+    let outer = h.spawn_view(root, ScrollView::default(), Vec2::new(300.0, 200.0), 0, 0.0);
+    let inner = h.spawn_view(
+        outer.content,
+        ScrollView::default(),
+        Vec2::new(200.0, 100.0),
+        10,
+        50.0,
+    );
+    h.spawn_items(outer.content, 10, 50.0, false);
+    h.settle();
+
+    let point = h.center_of(inner.view);
+    click(&mut h, point);
+    assert_eq!(active_view(&h), Some(inner.view));
+}
+
+#[test]
+fn keyboard_scrolls_the_hovered_view_without_an_active_one() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, keyboard_view());
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    // A page is the view height scaled by `ScrollInput::page_fraction`.
+    h.key(KeyCode::PageDown);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -90.0));
+    h.key(KeyCode::PageUp);
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
+
+#[test]
+fn keyboard_is_disabled_by_default() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    let point = h.center_of(v.view);
+    click(&mut h, point);
+
+    h.key(KeyCode::PageDown);
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
+
+#[test]
+fn wheel_scrolls_the_active_view_with_wheel_when_active() {
+    let mut h = Harness::new();
+    let scroll_view = || ScrollView {
+        input: ScrollInput {
+            wheel_when_active: true,
+            ..default()
+        },
+        ..default()
+    };
+    let first = vertical_view(&mut h, scroll_view());
+    let second = vertical_view(&mut h, scroll_view());
+    h.settle();
+
+    h.app.insert_resource(ActiveScrollView(Some(first.view)));
+    h.hover(h.center_of(second.view));
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(first.content), Vec2::new(0.0, -40.0));
+    assert_eq!(h.offset(second.content), Vec2::ZERO);
+
+    h.app.insert_resource(ActiveScrollView(None));
+    h.wheel_lines(0.0, -1.0);
+    assert_eq!(h.offset(first.content), Vec2::new(0.0, -40.0));
+    assert_eq!(h.offset(second.content), Vec2::new(0.0, -40.0));
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+fn layout_cache(h: &Harness, v: &TestView) -> ScrollLayoutCache {
+    h.app
+        .world()
+        .get::<ScrollLayoutCache>(v.content)
+        .unwrap()
+        .clone()
+}
+
+#[test]
+fn layout_cache_lists_the_children_offsets() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    let cache = layout_cache(&h, &v);
+    assert!(!cache.horizontal);
+    assert_eq!(cache.len(), 10);
+    assert_eq!(cache.offset_of(3), Some(150.0));
+    assert_eq!(cache.size_of(3), Some(50.0));
+    assert_eq!(cache.index_at(175.0), Some(3));
+    assert_eq!(cache.index_at(500.0), None);
+    let third = h.app.world().get::<Children>(v.content).unwrap()[2];
+    assert_eq!(cache.index_of(third), Some(2));
+}
+
+#[test]
+fn layout_cache_is_rebuilt_when_the_children_change() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.spawn_items(v.content, 2, 100.0, false);
+    h.settle();
+    let cache = layout_cache(&h, &v);
+    assert_eq!(cache.len(), 12);
+    assert_eq!(cache.offset_of(11), Some(600.0));
+    assert_eq!(cache.size_of(11), Some(100.0));
+
+    let first = h.app.world().get::<Children>(v.content).unwrap()[0];
+    h.app.world_mut().entity_mut(first).despawn_recursive();
+    h.settle();
+    let cache = layout_cache(&h, &v);
+    assert_eq!(cache.len(), 11);
+    assert_eq!(cache.offset_of(0), Some(0.0));
+    assert_eq!(cache.offset_of(10), Some(550.0));
+}
+
+#[test]
+fn layout_cache_is_rebuilt_when_the_axis_changes() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    assert!(!layout_cache(&h, &v).horizontal);
+
+    h.app
+        .world_mut()
+        .get_mut::<ScrollView>(v.view)
+        .unwrap()
+        .axis = ScrollAxis::Horizontal;
+    h.update();
+    let cache = layout_cache(&h, &v);
+    assert!(cache.horizontal);
+    assert_eq!(cache.size_of(0), Some(200.0));
+}
+
+#[test]
+fn scroll_to_index_aligns_the_interpolated_child() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+
+    h.send(ScrollToIndex {
+        view: v.view,
+        index: 6.0,
+        align: ScrollAnchor::Start,
+        smooth_time: 0.0,
+    });
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -300.0));
+
+    // Halfway between the third and fourth child, centered in the viewport.
+    h.send(ScrollToIndex {
+        view: v.view,
+        index: 2.5,
+        align: ScrollAnchor::Center,
+        smooth_time: 0.0,
+    });
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -100.0));
+}
+
+#[test]
+fn visible_children_follow_the_scroll_and_the_threshold() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.app
+        .world_mut()
+        .entity_mut(v.view)
+        .insert(VisibleChildren::with_threshold(0.5));
+    h.settle();
+    let items: Vec<Entity> = h
+        .app
+        .world()
+        .get::<Children>(v.content)
+        .unwrap()
+        .iter()
+        .copied()
+        .collect();
+    let visible = |h: &Harness| {
+        h.app
+            .world()
+            .get::<VisibleChildren>(v.view)
+            .unwrap()
+            .entities
+            .clone()
+    };
+    assert_eq!(visible(&h), items[0..2]);
+
+    // The viewport shows half of the second and fourth item.
+    h.set_offset(v.content, Vec2::new(0.0, -75.0));
+    h.update();
+    assert_eq!(visible(&h), items[1..4]);
+
+    h.set_offset(v.content, Vec2::new(0.0, -80.0));
+    h.update();
+    assert_eq!(visible(&h), items[2..4]);
+
+    h.app
+        .world_mut()
+        .get_mut::<VisibleChildren>(v.view)
+        .unwrap()
+        .threshold = 1.0;
+    h.update();
+    assert_eq!(visible(&h), items[2..3]);
+}
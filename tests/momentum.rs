@@ -0,0 +1,267 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+fn flinging_view(h: &mut Harness, bounce: bool) -> TestView {
+    let v = vertical_view(
+        h,
+        ScrollView {
+            animation: ScrollAnimation {
+                fling: true,
+                bounce,
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+    v
+}
+
+/// Drags 150 pixels in 5 frames and releases, the fling then carries the content
+/// well past the end.
+fn fast_fling(h: &mut Harness) {
+    h.press();
+    for _ in 0..5 {
+        h.drag(Vec2::new(0.0, -30.0));
+    }
+    h.release();
+}
+
+#[test]
+fn fling_continues_after_release() {
+    let mut h = Harness::new();
+    let v = flinging_view(&mut h, false);
+
+    fast_fling(&mut h);
+    let released_at = h.offset(v.content).y;
+    h.run(5);
+    assert!(h.offset(v.content).y < released_at);
+}
+
+#[test]
+fn fling_stops_hard_at_the_end_without_bounce() {
+    let mut h = Harness::new();
+    let v = flinging_view(&mut h, false);
+
+    fast_fling(&mut h);
+    for _ in 0..120 {
+        h.update();
+        assert!(h.offset(v.content).y >= -400.0);
+    }
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+    let momentum = h.app.world().get::<ScrollMomentum>(v.view).unwrap();
+    assert_eq!(momentum.velocity, Vec2::ZERO);
+}
+
+#[test]
+fn fling_bounces_past_the_end_and_springs_back() {
+    let mut h = Harness::new();
+    let v = flinging_view(&mut h, true);
+
+    fast_fling(&mut h);
+    let mut furthest = 0.0f32;
+    for _ in 0..240 {
+        h.update();
+        furthest = furthest.min(h.offset(v.content).y);
+    }
+    assert!(furthest < -400.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -400.0));
+}
+
+#[test]
+fn no_fling_when_disabled() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    fast_fling(&mut h);
+    let released_at = h.offset(v.content);
+    h.run(5);
+    assert_eq!(h.offset(v.content), released_at);
+}
+
+#[test]
+fn touch_fling_continues_after_the_touch_ends() {
+    let mut h = Harness::new();
+    let v = flinging_view(&mut h, false);
+
+    h.touch_start(h.center_of(v.view));
+    for _ in 0..5 {
+        h.touch_move(Vec2::new(0.0, -30.0));
+    }
+    h.touch_end();
+    let released_at = h.offset(v.content).y;
+    h.run(5);
+    assert!(h.offset(v.content).y < released_at);
+}
+
+#[test]
+fn slow_release_does_not_fling() {
+    let mut h = Harness::new();
+    let v = flinging_view(&mut h, false);
+
+    h.press();
+    for _ in 0..5 {
+        h.drag(Vec2::new(0.0, -1.0));
+    }
+    h.release();
+    let released_at = h.offset(v.content);
+    h.run(5);
+    assert_eq!(h.offset(v.content), released_at);
+}
+
+#[test]
+fn touch_down_catches_the_fling() {
+    let mut h = Harness::new();
+    let v = flinging_view(&mut h, false);
+
+    h.touch_start(h.center_of(v.view));
+    for _ in 0..5 {
+        h.touch_move(Vec2::new(0.0, -30.0));
+    }
+    h.touch_end();
+    h.run(3);
+
+    h.touch_start(h.center_of(v.view));
+    let caught_at = h.offset(v.content);
+    assert!(caught_at.y > -400.0);
+    h.run(5);
+    assert_eq!(h.offset(v.content), caught_at);
+    h.touch_end();
+    h.run(5);
+    assert_eq!(h.offset(v.content), caught_at);
+}
+
+/// Distance the content travels after `fast_fling` with given friction.
+fn fling_distance(fling_friction: f32) -> f32 {
+    let mut h = Harness::new();
+    let v = vertical_view(
+        &mut h,
+        ScrollView {
+            animation: ScrollAnimation {
+                fling: true,
+                fling_friction,
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    fast_fling(&mut h);
+    let released_at = h.offset(v.content).y;
+    h.run(120);
+    released_at - h.offset(v.content).y
+}
+
+#[test]
+fn higher_friction_stops_the_fling_sooner() {
+    let slow = fling_distance(40.0);
+    let fast = fling_distance(20.0);
+    assert!(slow > 0.0);
+    assert!(slow < fast);
+}
+
+#[test]
+fn elastic_overscroll_follows_the_drag_and_springs_back() {
+    let mut h = Harness::new();
+    let v = vertical_view(
+        &mut h,
+        ScrollView {
+            animation: ScrollAnimation {
+                overscroll: Overscroll::Elastic {
+                    max_px: 60.0,
+                    stiffness: 150.0,
+                },
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+
+    h.press();
+    h.drag(Vec2::new(0.0, 50.0));
+    let first = h.offset(v.content).y;
+    h.drag(Vec2::new(0.0, 50.0));
+    let second = h.offset(v.content).y;
+    assert!(first > 0.0 && first < 50.0);
+    assert!(second > first && second < 60.0);
+    // Dragging back moves the content into the range again.
+    h.drag(Vec2::new(0.0, -100.0));
+    assert!(h.offset(v.content).y.abs() < 0.01);
+    h.drag(Vec2::new(0.0, 50.0));
+    h.release();
+
+    h.run(120);
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+}
+
+fn flinging_view_with_mode(h: &mut Harness, boundary_mode: BoundaryMode) -> TestView {
+    let v = vertical_view(
+        h,
+        ScrollView {
+            animation: ScrollAnimation {
+                fling: true,
+                ..default()
+            },
+            bounds: ScrollBounds {
+                boundary_mode,
+                ..default()
+            },
+            ..default()
+        },
+    );
+    h.settle();
+    h.hover(h.center_of(v.view));
+    v
+}
+
+#[test]
+fn fling_continues_past_the_start_in_free_mode() {
+    let mut h = Harness::new();
+    let v = flinging_view_with_mode(&mut h, BoundaryMode::Free);
+
+    h.press();
+    for _ in 0..5 {
+        h.drag(Vec2::new(0.0, 30.0));
+    }
+    h.release();
+    let released_at = h.offset(v.content).y;
+    assert!(released_at > 0.0);
+    let mut previous = released_at;
+    for _ in 0..30 {
+        h.update();
+        let y = h.offset(v.content).y;
+        assert!(y >= previous);
+        previous = y;
+    }
+    assert!(previous > released_at);
+}
+
+#[test]
+fn fling_wraps_around_in_wrap_mode() {
+    let mut h = Harness::new();
+    let v = flinging_view_with_mode(&mut h, BoundaryMode::Wrap);
+
+    fast_fling(&mut h);
+    let mut previous = h.offset(v.content).y;
+    let mut wrapped = false;
+    for _ in 0..120 {
+        h.update();
+        let y = h.offset(v.content).y;
+        // The content keeps moving towards the end, so moving back means it wrapped.
+        wrapped |= y > previous;
+        assert!(y > -500.0 && y <= 0.0);
+        previous = y;
+    }
+    assert!(wrapped);
+    assert!(previous > -400.0);
+}
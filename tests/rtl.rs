@@ -0,0 +1,71 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+/// Right-to-left view of 200x100 with 10 items of 50 pixels in a `RowReverse` content,
+/// so the first item is on the right.
+fn rtl_view(h: &mut Harness) -> (TestView, Entity) {
+    let root = h.root;
+    let v = h.spawn_view(
+        root,
+        ScrollView {
+            axis: ScrollAxis::Horizontal,
+            right_to_left: true,
+            ..default()
+        },
+        Vec2::new(200.0, 100.0),
+        10,
+        50.0,
+    );
+    h.app
+        .world_mut()
+        .get_mut::<Style>(v.content)
+        .unwrap()
+        .flex_direction = FlexDirection::RowReverse;
+    h.settle();
+    h.hover(h.center_of(v.view));
+    let first = h.app.world().get::<Children>(v.content).unwrap()[0];
+    (v, first)
+}
+
+fn view_right(h: &Harness, v: &TestView) -> f32 {
+    h.center_of(v.view).x + 100.0
+}
+
+#[test]
+fn right_to_left_view_starts_with_the_first_item_on_the_right() {
+    let mut h = Harness::new();
+    let (v, first) = rtl_view(&mut h);
+
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+    assert_eq!(h.center_of(first).x, view_right(&h, &v) - 25.0);
+    let metrics = *h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.max_scroll, Vec2::new(300.0, 0.0));
+}
+
+#[test]
+fn right_to_left_view_scrolls_towards_the_left() {
+    let mut h = Harness::new();
+    let (v, first) = rtl_view(&mut h);
+
+    h.wheel_lines(0.0, -1.0);
+    h.update();
+    assert_eq!(h.offset(v.content), Vec2::new(-40.0, 0.0));
+    // The content moves right to reveal the items on the left.
+    assert_eq!(h.center_of(first).x, view_right(&h, &v) - 25.0 + 40.0);
+
+    // Dragging the pointer right pulls the content with it.
+    h.press();
+    h.drag(Vec2::new(30.0, 0.0));
+    h.release();
+    assert_eq!(h.offset(v.content), Vec2::new(-70.0, 0.0));
+    h.wheel_lines(0.0, -100.0);
+    assert_eq!(h.offset(v.content), Vec2::new(-300.0, 0.0));
+
+    h.send(ScrollTo::start(v.view));
+    h.update();
+    assert_eq!(h.offset(v.content), Vec2::ZERO);
+    assert_eq!(h.center_of(first).x, view_right(&h, &v) - 25.0);
+}
@@ -0,0 +1,190 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+fn view_with_scrollbar(h: &mut Harness, axis: ScrollAxis, layout: ScrollbarLayout) -> TestView {
+    let v = vertical_view(h, ScrollView { axis, ..default() });
+    h.app.world_mut().entity_mut(v.view).insert(Scrollbar {
+        width: 8.0,
+        layout,
+        ..default()
+    });
+    v
+}
+
+#[test]
+fn overlay_scrollbar_does_not_change_the_layout() {
+    let mut h = Harness::new();
+    let v = view_with_scrollbar(&mut h, ScrollAxis::Vertical, ScrollbarLayout::Overlay);
+    h.settle();
+
+    let metrics = *h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.content_size, Vec2::new(200.0, 500.0));
+    assert_eq!(metrics.max_scroll, Vec2::new(0.0, 400.0));
+}
+
+#[test]
+fn inset_scrollbar_reserves_a_gutter() {
+    let mut h = Harness::new();
+    let v = view_with_scrollbar(&mut h, ScrollAxis::Vertical, ScrollbarLayout::Inset);
+    h.settle();
+
+    let metrics = *h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.container_size, Vec2::new(200.0, 100.0));
+    assert_eq!(metrics.content_size, Vec2::new(192.0, 500.0));
+    assert_eq!(metrics.max_scroll, Vec2::new(0.0, 400.0));
+}
+
+#[test]
+fn inset_scrollbars_reduce_the_viewport() {
+    let mut h = Harness::new();
+    let v = view_with_scrollbar(&mut h, ScrollAxis::Both, ScrollbarLayout::Inset);
+    h.app
+        .world_mut()
+        .get_mut::<Style>(v.content)
+        .unwrap()
+        .min_width = Val::Px(300.0);
+    h.settle();
+
+    let metrics = *h.app.world().get::<ScrollMetrics>(v.view).unwrap();
+    assert_eq!(metrics.max_scroll, Vec2::new(108.0, 408.0));
+
+    h.hover(h.center_of(v.view));
+    h.wheel_lines(0.0, -20.0);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -408.0));
+}
+
+fn part<T: Component>(h: &mut Harness, view: Entity, get_view: fn(&T) -> Entity) -> Entity {
+    h.app
+        .world_mut()
+        .query::<(Entity, &T)>()
+        .iter(h.app.world())
+        .find(|(_, part)| get_view(part) == view)
+        .map(|(entity, _)| entity)
+        .unwrap()
+}
+
+#[test]
+fn thumb_is_proportional_to_the_visible_part() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.app.world_mut().entity_mut(v.view).insert(Scrollbar {
+        min_thumb_size: 0.0,
+        ..default()
+    });
+    h.settle();
+    let thumb = part::<ScrollbarThumb>(&mut h, v.view, |thumb| thumb.view);
+
+    let style = h.app.world().get::<Style>(thumb).unwrap();
+    assert_eq!(style.height, Val::Px(20.0));
+    assert_eq!(style.top, Val::Px(0.0));
+
+    h.set_offset(v.content, Vec2::new(0.0, -200.0));
+    h.update();
+    let style = h.app.world().get::<Style>(thumb).unwrap();
+    assert_eq!(style.top, Val::Px(40.0));
+}
+
+#[test]
+fn thumb_keeps_the_minimum_size_for_very_tall_content() {
+    let mut h = Harness::new();
+    let root = h.root;
+    let v = h.spawn_view(
+        root,
+        ScrollView::default(),
+        Vec2::new(200.0, 100.0),
+        200,
+        50.0,
+    );
+    h.app.world_mut().entity_mut(v.view).insert(Scrollbar {
+        min_thumb_size: 30.0,
+        ..default()
+    });
+    h.settle();
+    let thumb = part::<ScrollbarThumb>(&mut h, v.view, |thumb| thumb.view);
+
+    // The proportional thumb would be half a pixel long.
+    let style = h.app.world().get::<Style>(thumb).unwrap();
+    assert_eq!(style.height, Val::Px(30.0));
+    assert_eq!(style.top, Val::Px(0.0));
+
+    h.set_offset(v.content, Vec2::new(0.0, -9900.0));
+    h.update();
+    let style = h.app.world().get::<Style>(thumb).unwrap();
+    assert_eq!(style.height, Val::Px(30.0));
+    assert_eq!(style.top, Val::Px(70.0));
+}
+
+#[test]
+fn scrollbar_is_hidden_without_overflow() {
+    let mut h = Harness::new();
+    let root = h.root;
+    let v = h.spawn_view(
+        root,
+        ScrollView::default(),
+        Vec2::new(200.0, 100.0),
+        1,
+        50.0,
+    );
+    h.app
+        .world_mut()
+        .entity_mut(v.view)
+        .insert(Scrollbar::default());
+    h.settle();
+    let track = part::<ScrollbarTrack>(&mut h, v.view, |track| track.view);
+
+    let style = h.app.world().get::<Style>(track).unwrap();
+    assert_eq!(style.display, Display::None);
+}
+
+fn track(h: &mut Harness, view: Entity, horizontal: bool) -> Entity {
+    h.app
+        .world_mut()
+        .query::<(Entity, &ScrollbarTrack)>()
+        .iter(h.app.world())
+        .find(|(_, track)| track.view == view && track.horizontal == horizontal)
+        .map(|(entity, _)| entity)
+        .unwrap()
+}
+
+#[test]
+fn both_scrollbars_leave_the_corner_free() {
+    let mut h = Harness::new();
+    let v = view_with_scrollbar(&mut h, ScrollAxis::Both, ScrollbarLayout::Overlay);
+    h.app
+        .world_mut()
+        .get_mut::<Style>(v.content)
+        .unwrap()
+        .min_width = Val::Px(400.0);
+    h.settle();
+    let vertical = track(&mut h, v.view, false);
+    let horizontal = track(&mut h, v.view, true);
+    let corner = part::<ScrollbarCorner>(&mut h, v.view, |corner| corner.view);
+
+    let world = h.app.world();
+    assert_eq!(world.get::<Style>(vertical).unwrap().display, Display::Flex);
+    assert_eq!(world.get::<Style>(vertical).unwrap().bottom, Val::Px(8.0));
+    assert_eq!(
+        world.get::<Style>(horizontal).unwrap().display,
+        Display::Flex
+    );
+    assert_eq!(world.get::<Style>(horizontal).unwrap().right, Val::Px(8.0));
+    assert_eq!(world.get::<Style>(corner).unwrap().display, Display::Flex);
+
+    // Only the vertical scrollbar is left once the content fits horizontally.
+    h.app
+        .world_mut()
+        .get_mut::<Style>(v.content)
+        .unwrap()
+        .min_width = Val::Auto;
+    h.settle();
+    let world = h.app.world();
+    assert_eq!(world.get::<Style>(vertical).unwrap().display, Display::Flex);
+    assert_eq!(
+        world.get::<Style>(horizontal).unwrap().display,
+        Display::None
+    );
+    assert_eq!(world.get::<Style>(corner).unwrap().display, Display::None);
+}
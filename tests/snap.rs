@@ -0,0 +1,85 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_simple_scroll_view::*;
+use common::*;
+
+/// Horizontal view of 200x100 with 10 items of 50 pixels snapping with given alignment.
+fn carousel(h: &mut Harness, snap_align: ScrollAnchor) -> TestView {
+    let root = h.root;
+    let v = h.spawn_view(
+        root,
+        ScrollView {
+            axis: ScrollAxis::Horizontal,
+            ..default()
+        },
+        Vec2::new(200.0, 100.0),
+        10,
+        50.0,
+    );
+    h.app.world_mut().entity_mut(v.view).insert(ScrollSnap {
+        snap_align,
+        ..default()
+    });
+    h.settle();
+    v
+}
+
+#[test]
+fn snaps_the_nearest_child_to_the_start() {
+    let mut h = Harness::new();
+    let v = carousel(&mut h, ScrollAnchor::Start);
+
+    h.set_offset(v.content, Vec2::new(-70.0, 0.0));
+    h.run(60);
+    assert_eq!(h.offset(v.content), Vec2::new(-50.0, 0.0));
+}
+
+#[test]
+fn snaps_the_nearest_child_to_the_center() {
+    let mut h = Harness::new();
+    let v = carousel(&mut h, ScrollAnchor::Center);
+
+    h.set_offset(v.content, Vec2::new(-70.0, 0.0));
+    h.run(60);
+    assert_eq!(h.offset(v.content), Vec2::new(-75.0, 0.0));
+}
+
+#[test]
+fn does_not_snap_while_dragging() {
+    let mut h = Harness::new();
+    let v = carousel(&mut h, ScrollAnchor::Start);
+    h.hover(h.center_of(v.view));
+
+    h.press();
+    h.drag(Vec2::new(-70.0, 0.0));
+    h.run(30);
+    assert_eq!(h.offset(v.content), Vec2::new(-70.0, 0.0));
+    h.release();
+    h.run(60);
+    assert_eq!(h.offset(v.content), Vec2::new(-50.0, 0.0));
+}
+
+#[test]
+fn snaps_the_end_of_the_nearest_child_to_the_end() {
+    let mut h = Harness::new();
+    let v = vertical_view(&mut h, ScrollView::default());
+    h.app.world_mut().entity_mut(v.view).insert(ScrollSnap {
+        snap_align: ScrollAnchor::End,
+        ..default()
+    });
+    h.settle();
+
+    // The viewport ends at 185, closest to the end of the fourth item at 200.
+    h.set_offset(v.content, Vec2::new(0.0, -85.0));
+    h.run(60);
+    assert_eq!(h.offset(v.content), Vec2::new(0.0, -100.0));
+
+    let rect = |h: &Harness, entity: Entity| {
+        let world = h.app.world();
+        let node = world.get::<Node>(entity).unwrap();
+        node.logical_rect(world.get::<GlobalTransform>(entity).unwrap())
+    };
+    let child = h.app.world().get::<Children>(v.content).unwrap()[3];
+    assert_eq!(rect(&h, child).max.y, rect(&h, v.view).max.y);
+}